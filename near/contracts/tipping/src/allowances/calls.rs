@@ -0,0 +1,63 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Allows a delegate, such as a server, to tip from the deposit of the caller in a token.
+	/// Approving again replaces the allowance and resets today's spending.
+	pub fn approve_delegate(
+		&mut self,
+		delegate: AccountId,
+		ft_identifier: FtIdentifier,
+		daily_limit: U128,
+		per_tip_cap: U128,
+		expires_at: Option<Timestamp>,
+	) {
+		let account_id = env::predecessor_account_id();
+
+		assert!(account_id != delegate, "InvalidDelegate");
+		assert!(per_tip_cap.0 > 0, "InvalidAllowance");
+		assert!(per_tip_cap.0 <= daily_limit.0, "InvalidAllowance");
+
+		if let Some(expires_at) = expires_at {
+			assert!(expires_at > env::block_timestamp(), "InvalidExpiry");
+		}
+
+		let key = (account_id, delegate, ft_identifier);
+		let allowance = Allowance::new(daily_limit, per_tip_cap, expires_at);
+
+		self.allowances.insert(&key, &allowance);
+
+		log!("{} approved {} to tip {}", key.0, key.1, key.2);
+	}
+
+	pub fn revoke_delegate(&mut self, delegate: AccountId, ft_identifier: FtIdentifier) {
+		let account_id = env::predecessor_account_id();
+		let key = (account_id, delegate, ft_identifier);
+
+		self.allowances.remove(&key).expect("AllowanceNotExists");
+
+		log!("{} revoked {} from tipping {}", key.0, key.1, key.2);
+	}
+
+	/// Tips on behalf of an account from its deposit, within the allowance of the caller.
+	pub fn delegated_tip(
+		&mut self,
+		account_id: AccountId,
+		tips_balance_info: TipsBalanceInfo,
+		amount: U128,
+		memo: Option<String>,
+	) -> TipId {
+		let delegate = env::predecessor_account_id();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let key = (account_id.clone(), delegate, ft_identifier);
+
+		self.spend_allowance(&key, amount.0);
+		self.internal_tip_from_balance(
+			&account_id,
+			&tips_balance_info,
+			amount.0,
+			memo,
+			TipKind::Direct,
+		)
+	}
+}
@@ -0,0 +1,19 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn current_day(&self) -> u64 {
+		env::block_timestamp() / NANOSECONDS_PER_DAY
+	}
+
+	/// Checks a delegated tip against the allowance and counts it in today's spending.
+	pub(crate) fn spend_allowance(&mut self, key: &AllowanceKey, amount: Balance) {
+		let allowance = self.allowances.get(key).expect("AllowanceNotExists");
+		let day = self.current_day();
+
+		assert!(!allowance.is_expired(env::block_timestamp()), "AllowanceExpired");
+		assert!(amount <= allowance.get_per_tip_cap(), "ExceedsPerTipCap");
+		assert!(amount <= allowance.remaining_on(day), "ExceedsDailyLimit");
+
+		self.allowances.insert(key, &allowance.spend(day, amount));
+	}
+}
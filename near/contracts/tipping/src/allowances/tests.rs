@@ -0,0 +1,252 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod allowances_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn delegated_tip_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and approves accounts(4)
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.approve_delegate(
+			accounts(4),
+			String::from("native"),
+			U128(25),
+			U128(10),
+			Some(NANOSECONDS_PER_DAY * 2),
+		);
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// accounts(4) tips on behalf of accounts(3)
+		testing_env!(context.predecessor_account_id(accounts(4)).build());
+		contract.delegated_tip(accounts(3), tips_balance_info.clone(), U128(10), None);
+		contract.delegated_tip(accounts(3), tips_balance_info.clone(), U128(10), None);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+		let allowance = contract
+			.get_allowance(accounts(3), accounts(4), String::from("native"))
+			.unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 20);
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(80));
+		assert_eq!(allowance.remaining_today, U128(5));
+	}
+
+	#[test]
+	fn cancel_delegated_tip_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and approves accounts(4)
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.approve_delegate(
+			accounts(4),
+			String::from("native"),
+			U128(25),
+			U128(10),
+			Some(NANOSECONDS_PER_DAY * 2),
+		);
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// accounts(4) tips on behalf of accounts(3)
+		testing_env!(context.predecessor_account_id(accounts(4)).build());
+		let tip_id = contract.delegated_tip(accounts(3), tips_balance_info.clone(), U128(10), None);
+
+		// accounts(3) cancels the tip
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 0);
+		assert!(contract.get_tip_record(tip_id).unwrap().tip_record.is_cancelled());
+	}
+
+	#[test]
+	fn daily_limit_resets_next_day() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and approves accounts(4)
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.approve_delegate(
+			accounts(4),
+			String::from("native"),
+			U128(25),
+			U128(10),
+			Some(NANOSECONDS_PER_DAY * 2),
+		);
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// accounts(4) tips on behalf of accounts(3) on two days
+		testing_env!(context.predecessor_account_id(accounts(4)).build());
+		contract.delegated_tip(accounts(3), tips_balance_info.clone(), U128(10), None);
+		contract.delegated_tip(accounts(3), tips_balance_info.clone(), U128(10), None);
+
+		testing_env!(context.block_timestamp(NANOSECONDS_PER_DAY).build());
+		contract.delegated_tip(accounts(3), tips_balance_info.clone(), U128(10), None);
+
+		// Test
+		let allowance = contract
+			.get_allowance(accounts(3), accounts(4), String::from("native"))
+			.unwrap();
+
+		assert_eq!(allowance.spent_today, U128(10));
+	}
+
+	#[test]
+	#[should_panic(expected = "ExceedsDailyLimit")]
+	fn cant_delegated_tip_over_daily_limit() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and approves accounts(4)
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.approve_delegate(
+			accounts(4),
+			String::from("native"),
+			U128(25),
+			U128(10),
+			Some(NANOSECONDS_PER_DAY * 2),
+		);
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// accounts(4) tips on behalf of accounts(3)
+		testing_env!(context.predecessor_account_id(accounts(4)).build());
+		for _ in 0..3 {
+			contract.delegated_tip(accounts(3), tips_balance_info.clone(), U128(10), None);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "ExceedsPerTipCap")]
+	fn cant_delegated_tip_over_per_tip_cap() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and approves accounts(4)
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.approve_delegate(
+			accounts(4),
+			String::from("native"),
+			U128(25),
+			U128(10),
+			Some(NANOSECONDS_PER_DAY * 2),
+		);
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// accounts(4) tips on behalf of accounts(3)
+		testing_env!(context.predecessor_account_id(accounts(4)).build());
+		contract.delegated_tip(accounts(3), tips_balance_info, U128(11), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "AllowanceExpired")]
+	fn cant_delegated_tip_after_expiry() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and approves accounts(4)
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.approve_delegate(
+			accounts(4),
+			String::from("native"),
+			U128(25),
+			U128(10),
+			Some(NANOSECONDS_PER_DAY * 2),
+		);
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// accounts(4) tips on behalf of accounts(3)
+		testing_env!(context
+			.predecessor_account_id(accounts(4))
+			.block_timestamp(NANOSECONDS_PER_DAY * 2)
+			.build());
+		contract.delegated_tip(accounts(3), tips_balance_info, U128(10), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "AllowanceNotExists")]
+	fn cant_delegated_tip_after_revoke() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and approves accounts(4)
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.approve_delegate(
+			accounts(4),
+			String::from("native"),
+			U128(25),
+			U128(10),
+			Some(NANOSECONDS_PER_DAY * 2),
+		);
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// accounts(3) revokes accounts(4)
+		testing_env!(context.predecessor_account_id(accounts(3)).build());
+		contract.revoke_delegate(accounts(4), String::from("native"));
+
+		// accounts(4) tips on behalf of accounts(3)
+		testing_env!(context.predecessor_account_id(accounts(4)).build());
+		contract.delegated_tip(accounts(3), tips_balance_info, U128(10), None);
+	}
+}
@@ -0,0 +1,69 @@
+use crate::*;
+
+/// Owner of the deposit, delegate allowed to tip from it and token.
+pub type AllowanceKey = (AccountId, AccountId, FtIdentifier);
+
+/// Lets a delegate tip from the deposit of an account. Spending is counted per UTC day.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Allowance {
+	daily_limit: U128,
+	per_tip_cap: U128,
+	expires_at: Option<Timestamp>,
+	day: u64,
+	spent_today: U128,
+}
+impl Allowance {
+	pub fn new(daily_limit: U128, per_tip_cap: U128, expires_at: Option<Timestamp>) -> Self {
+		Self {
+			daily_limit,
+			per_tip_cap,
+			expires_at,
+			day: Zero::zero(),
+			spent_today: U128(Zero::zero()),
+		}
+	}
+
+	pub fn get_daily_limit(&self) -> Balance {
+		self.daily_limit.0
+	}
+
+	pub fn get_per_tip_cap(&self) -> Balance {
+		self.per_tip_cap.0
+	}
+
+	pub fn get_expires_at(&self) -> Option<Timestamp> {
+		self.expires_at
+	}
+
+	pub fn is_expired(&self, now: Timestamp) -> bool {
+		matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+	}
+
+	pub fn spent_on(&self, day: u64) -> Balance {
+		if self.day == day {
+			self.spent_today.0
+		} else {
+			Zero::zero()
+		}
+	}
+
+	pub fn remaining_on(&self, day: u64) -> Balance {
+		self.get_daily_limit().saturating_sub(self.spent_on(day))
+	}
+
+	pub fn spend(mut self, day: u64, amount: Balance) -> Self {
+		self.spent_today = U128(self.spent_on(day) + amount);
+		self.day = day;
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AllowanceWithUsage {
+	pub allowance: Allowance,
+	pub spent_today: U128,
+	pub remaining_today: U128,
+	pub is_expired: bool,
+}
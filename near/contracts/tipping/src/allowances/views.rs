@@ -0,0 +1,21 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_allowance(
+		&self,
+		account_id: AccountId,
+		delegate: AccountId,
+		ft_identifier: FtIdentifier,
+	) -> Option<AllowanceWithUsage> {
+		let allowance = self.allowances.get(&(account_id, delegate, ft_identifier))?;
+		let day = self.current_day();
+
+		Some(AllowanceWithUsage {
+			spent_today: U128(allowance.spent_on(day)),
+			remaining_today: U128(allowance.remaining_on(day)),
+			is_expired: allowance.is_expired(env::block_timestamp()),
+			allowance,
+		})
+	}
+}
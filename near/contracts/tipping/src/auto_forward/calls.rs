@@ -0,0 +1,16 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Opts the caller in or out of receiving tips to its claimed references right away, instead
+	/// of claiming them.
+	pub fn set_auto_forward(&mut self, enabled: bool) {
+		let account_id = env::predecessor_account_id();
+
+		if enabled {
+			self.auto_forward_accounts.insert(&account_id);
+		} else {
+			self.auto_forward_accounts.remove(&account_id);
+		}
+	}
+}
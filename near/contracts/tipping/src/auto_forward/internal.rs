@@ -0,0 +1,61 @@
+use crate::*;
+
+impl Tipping {
+	/// Transfers a tip that was just credited to the account of a claimed reference when the
+	/// account opted in. The tip stays in the balance while claims are paused or while a campaign
+	/// locks it.
+	pub(crate) fn forward_tip(&mut self, tips_balance: TipsBalance, amount: Balance) {
+		let account_id = match tips_balance.get_account_id() {
+			Some(account_id) if self.auto_forward_accounts.contains(account_id) =>
+				account_id.clone(),
+			_ => return,
+		};
+		let ft_identifier = tips_balance.get_ft_identifier().to_string();
+		let key = tips_balance.key();
+
+		if self.is_paused(PauseFeature::Claiming, Some(&ft_identifier)) {
+			return
+		}
+
+		if self.is_reference_blocked(tips_balance.get_tips_balance_info()) {
+			return
+		}
+
+		if !self.campaign_locked_amount(&key).is_zero() {
+			return
+		}
+
+		let remaining = tips_balance.get_amount().checked_sub(amount).expect("InsufficientBalance");
+		let payouts = self.revenue_payouts(&tips_balance, &account_id, amount);
+
+		self.tips_balances
+			.insert(&key, &tips_balance.set_balance(remaining).set_claimed());
+		self.transfer_payouts(&ft_identifier, &payouts)
+			.then(Self::ext(env::current_account_id()).resolve_forward_tip(key, payouts));
+	}
+
+	pub(crate) fn internal_resolve_forward_tip(
+		&mut self,
+		tips_balance_key: TipsBalanceKey,
+		payouts: Vec<Payout>,
+	) {
+		require!(env::promise_results_count() == payouts.len() as u64);
+
+		let tips_balance = self.tips_balances.get(&tips_balance_key).expect("TipsBalanceNotExists");
+		let ft_identifier = tips_balance.get_ft_identifier().to_string();
+		let paid_payouts = self.paid_payouts(&payouts, 0);
+		let total: Balance = payouts.iter().map(|payout| payout.amount.0).sum();
+		let paid: Balance = paid_payouts.iter().map(|payout| payout.amount.0).sum();
+
+		for payout in paid_payouts {
+			let (symbol, formatted) = self.formatted_amount_of(&ft_identifier, payout.amount.0);
+
+			log!("{} received {} {}", payout.account_id, formatted, symbol);
+		}
+
+		if total > paid {
+			self.tips_balances
+				.insert(&tips_balance_key, &tips_balance.add_balance(total - paid));
+		}
+	}
+}
@@ -0,0 +1,5 @@
+pub mod calls;
+pub mod internal;
+pub mod private_calls;
+pub mod tests;
+pub mod views;
@@ -0,0 +1,9 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	#[private]
+	pub fn resolve_forward_tip(&mut self, tips_balance_key: TipsBalanceKey, payouts: Vec<Payout>) {
+		self.internal_resolve_forward_tip(tips_balance_key, payouts);
+	}
+}
@@ -0,0 +1,152 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod auto_forward_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env, RuntimeFeesConfig, VMConfig,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn send_tip_is_forwarded() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) enables auto forward
+		testing_env!(context.predecessor_account_id(accounts(2)).build());
+		contract.set_auto_forward(true);
+
+		// Send tip to post_1
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert!(contract.is_auto_forward(accounts(2)));
+		assert_eq!(tips_balance.tips_balance.get_amount(), 0);
+		assert_eq!(tips_balance.tips_balance.get_total_amount(), 100);
+	}
+
+	#[test]
+	fn failed_forward_falls_back_to_balance() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) enables auto forward
+		testing_env!(context.predecessor_account_id(accounts(2)).build());
+		contract.set_auto_forward(true);
+
+		// Send tip to post_1
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Resolve the failed transfer
+		let payouts = vec![Payout { account_id: accounts(2), amount: U128(100) }];
+
+		testing_env!(
+			context.predecessor_account_id(accounts(0)).build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![PromiseResult::Failed],
+		);
+		contract.resolve_forward_tip(tips_balance_info.key(), payouts);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 100);
+	}
+
+	#[test]
+	fn tip_to_blocked_reference_is_not_forwarded() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) enables auto forward
+		testing_env!(context.predecessor_account_id(accounts(2)).build());
+		contract.set_auto_forward(true);
+
+		// Owner blocks post_1
+		testing_env!(context.predecessor_account_id(accounts(1)).build());
+		contract.block_reference(
+			accounts(4),
+			String::from("post"),
+			String::from("post_1"),
+			String::from("Abuse"),
+		);
+
+		// Credit a tip to post_1
+		contract.create_tips_balance(&accounts(3), &tips_balance_info, &100, None, TipKind::Credit);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 100);
+	}
+
+	#[test]
+	fn send_tip_is_not_forwarded_when_disabled() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) enables auto forward
+		testing_env!(context.predecessor_account_id(accounts(2)).build());
+		contract.set_auto_forward(true);
+
+		// accounts(2) disables auto forward
+		testing_env!(context.predecessor_account_id(accounts(2)).build());
+		contract.set_auto_forward(false);
+
+		// Send tip to post_1
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 100);
+	}
+}
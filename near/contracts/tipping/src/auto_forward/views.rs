@@ -0,0 +1,8 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn is_auto_forward(&self, account_id: AccountId) -> bool {
+		self.auto_forward_accounts.contains(&account_id)
+	}
+}
@@ -0,0 +1,127 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Escrows an amount of the deposit of the caller against a reference until the deadline.
+	#[payable]
+	pub fn create_bounty(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		amount: U128,
+		deadline: Timestamp,
+	) -> BountyId {
+		let creator = env::predecessor_account_id();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let creator_index = BountyIndex::Creator(creator.clone());
+		let reference_index = BountyIndex::Reference(tips_balance_info.key());
+
+		assert!(amount.0 > 0, "NothingToDeposit");
+		assert!(deadline > env::block_timestamp(), "InvalidDeadline");
+		assert!(
+			self.bounty_ids(&creator_index).len() < MAX_BOUNTIES_PER_CREATOR,
+			"TooManyBounties"
+		);
+		assert!(
+			self.bounty_ids(&reference_index).len() < MAX_BOUNTIES_PER_REFERENCE,
+			"TooManyBounties"
+		);
+		assert!(self.ft_identifiers.get(&ft_identifier).is_some(), "TokenNotFound");
+		self.assert_not_paused(PauseFeature::Tipping, Some(&ft_identifier));
+		self.assert_not_blocked(&creator, &tips_balance_info);
+
+		self.add_attached_deposit(&creator);
+
+		self.remove_deposit(&creator, &ft_identifier, amount.0);
+
+		let id = self.next_bounty_id;
+		let bounty = Bounty::new(id, &creator, &tips_balance_info, amount.0, deadline);
+
+		self.next_bounty_id += 1;
+		self.bounties.insert(&id, &bounty);
+		self.add_bounty_id(&creator_index, id);
+		self.add_bounty_id(&reference_index, id);
+
+		log!("{} posted bounty {} on {}", creator, id, tips_balance_info.reference());
+
+		id
+	}
+
+	/// Awards the bounty, fully or in parts, to answer references before the deadline. Every award
+	/// is credited to the `TipsBalance` of the answer as a tip from the creator.
+	pub fn award_bounty(
+		&mut self,
+		bounty_id: BountyId,
+		awards: Vec<BatchTip>,
+		memo: Option<String>,
+	) -> Vec<TipId> {
+		let bounty = self.bounties.get(&bounty_id).expect("BountyNotExists");
+		let creator = env::predecessor_account_id();
+		let bounty_key = bounty.get_tips_balance_info().key();
+		let ft_identifier = bounty.get_tips_balance_info().get_ft_identifier().to_string();
+		let total = awards.iter().try_fold(0u128, |total, (_, amount)| total.checked_add(amount.0));
+
+		assert!(bounty.get_creator() == &creator, "Unauthorized");
+		assert!(!bounty.is_expired(env::block_timestamp()), "BountyExpired");
+		assert!(!awards.is_empty(), "NothingToTip");
+		assert!(awards.len() <= MAX_BATCH_TIPS, "TooManyTips");
+		assert!(total.is_some_and(|total| total <= bounty.remaining()), "InvalidTipsAmount");
+		self.assert_not_paused(PauseFeature::Tipping, Some(&ft_identifier));
+		self.assert_memo(&memo);
+
+		let bounty = bounty.award(total.unwrap());
+
+		if bounty.remaining().is_zero() {
+			self.remove_bounty(&bounty);
+		} else {
+			self.bounties.insert(&bounty_id, &bounty);
+		}
+
+		awards
+			.into_iter()
+			.map(|(tips_balance_info, amount)| {
+				let amount = amount.0;
+
+				assert!(
+					tips_balance_info.get_ft_identifier() == ft_identifier,
+					"InvalidFtIdentifier"
+				);
+				assert!(tips_balance_info.key() != bounty_key, "InvalidAnswer");
+				assert!(amount > 0, "NothingToTip");
+				self.assert_not_blocked(&creator, &tips_balance_info);
+
+				let (symbol, formatted) = self.formatted_amount_of(&ft_identifier, amount);
+				let reference = tips_balance_info.reference();
+				let tip_id = self.create_tips_balance(
+					&creator,
+					&tips_balance_info,
+					&amount,
+					memo.clone(),
+					TipKind::Credit,
+				);
+
+				log!("Bounty {} awarded {} {} to {}", bounty_id, formatted, symbol, reference);
+
+				tip_id
+			})
+			.collect()
+	}
+
+	/// Puts what was not awarded back in the deposit of the creator once the deadline passed,
+	/// callable by anyone.
+	pub fn refund_bounty(&mut self, bounty_id: BountyId) -> U128 {
+		let bounty = self.bounties.get(&bounty_id).expect("BountyNotExists");
+		let creator = bounty.get_creator().clone();
+		let ft_identifier = bounty.get_tips_balance_info().get_ft_identifier().to_string();
+		let remainder = bounty.remaining();
+
+		assert!(bounty.is_expired(env::block_timestamp()), "BountyNotExpired");
+		self.assert_not_paused(PauseFeature::Claiming, Some(&ft_identifier));
+
+		self.remove_bounty(&bounty);
+		self.add_deposit(&creator, &ft_identifier, remainder);
+
+		log!("Bounty {} refunded to {}", bounty_id, creator);
+
+		U128(remainder)
+	}
+}
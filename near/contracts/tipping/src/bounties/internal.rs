@@ -0,0 +1,41 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn bounty_ids(&self, index: &BountyIndex) -> Vec<BountyId> {
+		self.bounty_ids.get(index).unwrap_or_default()
+	}
+
+	pub(crate) fn add_bounty_id(&mut self, index: &BountyIndex, id: BountyId) {
+		let mut bounty_ids = self.bounty_ids(index);
+
+		bounty_ids.push(id);
+
+		self.bounty_ids.insert(index, &bounty_ids);
+	}
+
+	pub(crate) fn remove_bounty_id(&mut self, index: &BountyIndex, id: BountyId) {
+		let mut bounty_ids = self.bounty_ids(index);
+
+		bounty_ids.retain(|bounty_id| *bounty_id != id);
+
+		if bounty_ids.is_empty() {
+			self.bounty_ids.remove(index);
+		} else {
+			self.bounty_ids.insert(index, &bounty_ids);
+		}
+	}
+
+	pub(crate) fn bounties_by_index(&self, index: &BountyIndex) -> Vec<Bounty> {
+		self.bounty_ids(index).iter().filter_map(|id| self.bounties.get(id)).collect()
+	}
+
+	pub(crate) fn remove_bounty(&mut self, bounty: &Bounty) {
+		let id = bounty.get_id();
+		let creator_index = BountyIndex::Creator(bounty.get_creator().clone());
+		let reference_index = BountyIndex::Reference(bounty.get_tips_balance_info().key());
+
+		self.bounties.remove(&id);
+		self.remove_bounty_id(&creator_index, id);
+		self.remove_bounty_id(&reference_index, id);
+	}
+}
@@ -0,0 +1,219 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod bounties_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn award_bounty_splits_between_answers() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) posts a bounty of 100 on question_1 until timestamp 1000
+		let question_1 = TipsBalanceInfo::new(&accounts(4), "post", "question_1", "native");
+
+		testing_env!(context.attached_deposit(100).build());
+		let id = contract.create_bounty(question_1, U128(100), 1000);
+
+		// Payload
+		let answer_1 = TipsBalanceInfo::new(&accounts(4), "comment", "answer_1", "native");
+		let answer_2 = TipsBalanceInfo::new(&accounts(4), "comment", "answer_2", "native");
+		let awards = vec![(answer_1.clone(), U128(70)), (answer_2.clone(), U128(30))];
+
+		// Test
+		testing_env!(context.attached_deposit(0).build());
+		let tip_ids = contract.award_bounty(id, awards, None);
+
+		let answer_1_balance = contract.get_tips_balance(answer_1).unwrap();
+		let answer_2_balance = contract.get_tips_balance(answer_2).unwrap();
+
+		assert_eq!(tip_ids.len(), 2);
+		assert_eq!(answer_1_balance.tips_balance.get_amount(), 70);
+		assert_eq!(answer_2_balance.tips_balance.get_amount(), 30);
+		assert!(contract.get_bounty(id).is_none());
+		assert!(contract.get_bounties_by_creator(accounts(1)).is_empty());
+	}
+
+	#[test]
+	#[should_panic(expected = "TipNotCancellable")]
+	fn cant_cancel_bounty_award() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) posts a bounty of 100 on question_1 until timestamp 1000
+		let question_1 = TipsBalanceInfo::new(&accounts(4), "post", "question_1", "native");
+
+		testing_env!(context.attached_deposit(100).build());
+		let id = contract.create_bounty(question_1, U128(100), 1000);
+
+		// Payload
+		let answer_1 = TipsBalanceInfo::new(&accounts(4), "comment", "answer_1", "native");
+
+		// Award the bounty to answer_1
+		testing_env!(context.attached_deposit(0).build());
+		let tip_ids = contract.award_bounty(id, vec![(answer_1, U128(100))], None);
+
+		// Test
+		testing_env!(context.attached_deposit(1).build());
+		contract.cancel_tip(tip_ids[0]);
+	}
+
+	#[test]
+	fn partial_award_keeps_remainder() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) posts a bounty of 100 on question_1 until timestamp 1000
+		let question_1 = TipsBalanceInfo::new(&accounts(4), "post", "question_1", "native");
+
+		testing_env!(context.attached_deposit(100).build());
+		let id = contract.create_bounty(question_1, U128(100), 1000);
+
+		// Payload
+		let answer_1 = TipsBalanceInfo::new(&accounts(4), "comment", "answer_1", "native");
+
+		// Test
+		testing_env!(context.attached_deposit(0).build());
+		contract.award_bounty(id, vec![(answer_1, U128(40))], None);
+
+		let bounty = contract.get_bounty(id).unwrap();
+		let question_1 = bounty.get_tips_balance_info().clone();
+
+		assert_eq!(bounty.get_awarded(), 40);
+		assert_eq!(bounty.remaining(), 60);
+		assert_eq!(contract.get_bounties_by_reference(question_1).len(), 1);
+	}
+
+	#[test]
+	fn refund_bounty_after_deadline() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) posts a bounty of 100 on question_1 until timestamp 1000
+		let question_1 = TipsBalanceInfo::new(&accounts(4), "post", "question_1", "native");
+
+		testing_env!(context.attached_deposit(100).build());
+		let id = contract.create_bounty(question_1, U128(100), 1000);
+
+		// Payload
+		let answer_1 = TipsBalanceInfo::new(&accounts(4), "comment", "answer_1", "native");
+
+		// Award part of the bounty
+		testing_env!(context.attached_deposit(0).build());
+		contract.award_bounty(id, vec![(answer_1, U128(40))], None);
+
+		// Anyone refunds the rest after the deadline
+		testing_env!(context.predecessor_account_id(accounts(3)).block_timestamp(1000).build());
+		let remainder = contract.refund_bounty(id);
+
+		// Test
+		assert_eq!(remainder.0, 60);
+		assert_eq!(contract.get_deposit(accounts(1), String::from("native")).0, 60);
+		assert!(contract.get_bounty(id).is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "BountyNotExpired")]
+	fn cant_refund_bounty_before_deadline() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) posts a bounty of 100 on question_1 until timestamp 1000
+		let question_1 = TipsBalanceInfo::new(&accounts(4), "post", "question_1", "native");
+
+		testing_env!(context.attached_deposit(100).build());
+		let id = contract.create_bounty(question_1, U128(100), 1000);
+
+		// Test
+		testing_env!(context.attached_deposit(0).build());
+		contract.refund_bounty(id);
+	}
+
+	#[test]
+	#[should_panic(expected = "BountyExpired")]
+	fn cant_award_bounty_after_deadline() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) posts a bounty of 100 on question_1 until timestamp 1000
+		let question_1 = TipsBalanceInfo::new(&accounts(4), "post", "question_1", "native");
+
+		testing_env!(context.attached_deposit(100).build());
+		let id = contract.create_bounty(question_1, U128(100), 1000);
+
+		// Payload
+		let answer_1 = TipsBalanceInfo::new(&accounts(4), "comment", "answer_1", "native");
+
+		// Test
+		testing_env!(context.block_timestamp(1000).attached_deposit(0).build());
+		contract.award_bounty(id, vec![(answer_1, U128(40))], None);
+	}
+
+	#[test]
+	#[should_panic(expected = "InvalidTipsAmount")]
+	fn cant_award_more_than_bounty() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) posts a bounty of 100 on question_1 until timestamp 1000
+		let question_1 = TipsBalanceInfo::new(&accounts(4), "post", "question_1", "native");
+
+		testing_env!(context.attached_deposit(100).build());
+		let id = contract.create_bounty(question_1, U128(100), 1000);
+
+		// Payload
+		let answer_1 = TipsBalanceInfo::new(&accounts(4), "comment", "answer_1", "native");
+
+		// Test
+		testing_env!(context.attached_deposit(0).build());
+		contract.award_bounty(id, vec![(answer_1, U128(101))], None);
+	}
+
+	#[test]
+	#[should_panic(expected = "Unauthorized")]
+	fn cant_award_bounty_when_not_creator() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) posts a bounty of 100 on question_1 until timestamp 1000
+		let question_1 = TipsBalanceInfo::new(&accounts(4), "post", "question_1", "native");
+
+		testing_env!(context.attached_deposit(100).build());
+		let id = contract.create_bounty(question_1, U128(100), 1000);
+
+		// Payload
+		let answer_1 = TipsBalanceInfo::new(&accounts(4), "comment", "answer_1", "native");
+
+		// Test
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(0).build());
+		contract.award_bounty(id, vec![(answer_1, U128(40))], None);
+	}
+}
@@ -0,0 +1,82 @@
+use crate::*;
+
+pub type BountyId = u64;
+
+pub const MAX_BOUNTIES_PER_REFERENCE: usize = 10;
+pub const MAX_BOUNTIES_PER_CREATOR: usize = 20;
+
+/// Amount escrowed against a reference, usually a question, that the creator awards to answer
+/// references before the deadline. What is not awarded goes back to the creator afterwards.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Bounty {
+	id: BountyId,
+	creator: AccountId,
+	tips_balance_info: TipsBalanceInfo,
+	amount: U128,
+	awarded: U128,
+	deadline: Timestamp,
+	created_at: Timestamp,
+}
+impl Bounty {
+	pub fn new(
+		id: BountyId,
+		creator: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+		deadline: Timestamp,
+	) -> Self {
+		Self {
+			id,
+			creator: creator.clone(),
+			tips_balance_info: tips_balance_info.clone(),
+			amount: U128(amount),
+			awarded: U128(Zero::zero()),
+			deadline,
+			created_at: env::block_timestamp(),
+		}
+	}
+
+	pub fn get_id(&self) -> BountyId {
+		self.id
+	}
+
+	pub fn get_creator(&self) -> &AccountId {
+		&self.creator
+	}
+
+	pub fn get_tips_balance_info(&self) -> &TipsBalanceInfo {
+		&self.tips_balance_info
+	}
+
+	pub fn get_amount(&self) -> Balance {
+		self.amount.0
+	}
+
+	pub fn get_awarded(&self) -> Balance {
+		self.awarded.0
+	}
+
+	pub fn get_deadline(&self) -> Timestamp {
+		self.deadline
+	}
+
+	pub fn remaining(&self) -> Balance {
+		self.get_amount() - self.get_awarded()
+	}
+
+	pub fn is_expired(&self, now: Timestamp) -> bool {
+		now >= self.deadline
+	}
+
+	pub fn award(mut self, amount: Balance) -> Self {
+		self.awarded = U128(self.get_awarded() + amount);
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, Eq)]
+pub enum BountyIndex {
+	Reference(TipsBalanceKey),
+	Creator(AccountId),
+}
@@ -0,0 +1,16 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_bounty(&self, bounty_id: BountyId) -> Option<Bounty> {
+		self.bounties.get(&bounty_id)
+	}
+
+	pub fn get_bounties_by_creator(&self, creator: AccountId) -> Vec<Bounty> {
+		self.bounties_by_index(&BountyIndex::Creator(creator))
+	}
+
+	pub fn get_bounties_by_reference(&self, tips_balance_info: TipsBalanceInfo) -> Vec<Bounty> {
+		self.bounties_by_index(&BountyIndex::Reference(tips_balance_info.key()))
+	}
+}
@@ -0,0 +1,74 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Starts a crowdfunding campaign on a `TipsBalance` of a claimed reference. Only the account
+	/// the reference was claimed for can start it.
+	#[payable]
+	pub fn create_campaign(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		goal: U128,
+		deadline: Timestamp,
+		mode: CampaignMode,
+	) -> u64 {
+		assert_one_yocto();
+		self.assert_reference_owner(&tips_balance_info);
+
+		let key = tips_balance_info.key();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let now = env::block_timestamp();
+
+		assert!(goal.0 > 0, "InvalidGoal");
+		assert!(deadline > now, "InvalidDeadline");
+		assert!(self.ft_identifiers.get(&ft_identifier).is_some(), "TokenNotFound");
+
+		let round = match self.campaigns.get(&key) {
+			Some(campaign) => {
+				assert!(campaign.is_settled(now), "CampaignInProgress");
+				campaign.get_round() + 1
+			},
+			None => 0,
+		};
+
+		self.campaigns.insert(&key, &Campaign::new(round, goal.0, deadline, mode));
+
+		log!("Campaign started on {}", tips_balance_info.reference());
+
+		round
+	}
+
+	/// Sends the contribution of the caller back when an all-or-nothing campaign failed.
+	#[payable]
+	pub fn refund_campaign_contribution(&mut self, tips_balance_info: TipsBalanceInfo) {
+		assert_one_yocto();
+
+		let key = tips_balance_info.key();
+		let sender = env::predecessor_account_id();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let campaign = self.campaigns.get(&key).expect("CampaignNotExists");
+		let amount = self.campaign_contribution_of(&key, &sender);
+
+		self.assert_not_paused(PauseFeature::Claiming, Some(&ft_identifier));
+		assert!(
+			campaign.status(env::block_timestamp()) == CampaignStatus::Failed,
+			"CampaignNotFailed"
+		);
+		assert!(amount > 0, "NothingToRefund");
+
+		let tips_balance = self.tips_balances.get(&key).expect("TipsBalanceNotExists");
+		let remaining = tips_balance.get_amount().checked_sub(amount).expect("InsufficientBalance");
+
+		self.campaign_contributions.remove(&(key.clone(), sender.clone()));
+		self.campaigns.insert(&key, &campaign.add_refunded(amount));
+		self.tips_balances.insert(&key, &tips_balance.set_balance(remaining));
+
+		self.transfer(&sender, &ft_identifier, amount).then(
+			Self::ext(env::current_account_id()).resolve_refund_campaign_contribution(
+				tips_balance_info,
+				sender,
+				amount,
+			),
+		);
+	}
+}
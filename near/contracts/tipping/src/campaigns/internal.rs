@@ -0,0 +1,129 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn campaign_locked_amount(&self, key: &TipsBalanceKey) -> Balance {
+		match self.campaigns.get(key) {
+			Some(campaign) => campaign.locked_amount(env::block_timestamp()),
+			None => Zero::zero(),
+		}
+	}
+
+	/// Counts a tip towards the active campaign of its balance, if any, and returns the round it
+	/// was counted in.
+	pub(crate) fn add_campaign_contribution(
+		&mut self,
+		sender: &AccountId,
+		key: &TipsBalanceKey,
+		amount: Balance,
+	) -> Option<u64> {
+		let campaign = match self.campaigns.get(key) {
+			Some(campaign) if campaign.status(env::block_timestamp()) == CampaignStatus::Active =>
+				campaign,
+			_ => return None,
+		};
+		let round = campaign.get_round();
+		let contribution_key = (key.clone(), sender.clone());
+		let contribution = match self.campaign_contributions.get(&contribution_key) {
+			Some(contribution) if contribution.get_round() == campaign.get_round() => contribution,
+			_ => Contribution::new(campaign.get_round()),
+		};
+
+		self.campaign_contributions.insert(&contribution_key, &contribution.add(amount));
+		self.campaigns.insert(key, &campaign.add_raised(amount));
+
+		Some(round)
+	}
+
+	/// Takes a cancelled tip back out of the campaign it counted towards, or puts it back when
+	/// the cancellation failed. A tip whose contribution was already refunded, or whose campaign
+	/// was replaced by a new one, can't be cancelled.
+	pub(crate) fn apply_campaign_cancellation(&mut self, tip_record: &TipRecord, cancelled: bool) {
+		let round = match tip_record.get_campaign_round() {
+			Some(round) => round,
+			None => return,
+		};
+		let key = tip_record.get_key();
+		let amount = tip_record.get_amount();
+		let campaign = match self.campaigns.get(key) {
+			Some(campaign) if campaign.get_round() == round => campaign,
+			_ => {
+				assert!(!cancelled, "TipCountedInCampaign");
+				return
+			},
+		};
+		let contribution_key = (key.clone(), tip_record.get_sender().clone());
+		let contribution = match self.campaign_contributions.get(&contribution_key) {
+			Some(contribution) if contribution.get_round() == round => contribution,
+			_ => Contribution::new(round),
+		};
+
+		if cancelled {
+			assert!(contribution.get_amount() >= amount, "TipAlreadyRefunded");
+
+			let contribution = contribution.remove(amount);
+
+			if contribution.get_amount().is_zero() {
+				self.campaign_contributions.remove(&contribution_key);
+			} else {
+				self.campaign_contributions.insert(&contribution_key, &contribution);
+			}
+
+			self.campaigns.insert(key, &campaign.remove_raised(amount));
+		} else {
+			self.campaign_contributions.insert(&contribution_key, &contribution.add(amount));
+			self.campaigns.insert(key, &campaign.add_raised(amount));
+		}
+	}
+
+	pub(crate) fn campaign_contribution_of(
+		&self,
+		key: &TipsBalanceKey,
+		sender: &AccountId,
+	) -> Balance {
+		let campaign = match self.campaigns.get(key) {
+			Some(campaign) => campaign,
+			None => return Zero::zero(),
+		};
+
+		match self.campaign_contributions.get(&(key.clone(), sender.clone())) {
+			Some(contribution) if contribution.get_round() == campaign.get_round() =>
+				contribution.get_amount(),
+			_ => Zero::zero(),
+		}
+	}
+
+	pub(crate) fn internal_resolve_refund_campaign_contribution(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		sender: AccountId,
+		amount: Balance,
+	) {
+		require!(env::promise_results_count() == 1);
+
+		let key = tips_balance_info.key();
+		let ft_identifier = tips_balance_info.get_ft_identifier();
+
+		match env::promise_result(0) {
+			PromiseResult::Successful(_) => {
+				let (symbol, formatted) = self.formatted_amount_of(ft_identifier, amount);
+				let reference = tips_balance_info.reference();
+
+				self.reduce_supporter_total(&key, &sender, amount);
+
+				log!("{} refunded {} {} from {}", sender, formatted, symbol, reference);
+			},
+			_ => {
+				let campaign = self.campaigns.get(&key).expect("CampaignNotExists");
+				let contribution_key = (key.clone(), sender.clone());
+				let contribution = Contribution::new(campaign.get_round()).add(amount);
+
+				self.campaign_contributions.insert(&contribution_key, &contribution);
+				self.campaigns.insert(&key, &campaign.remove_refunded(amount));
+
+				if let Some(tips_balance) = self.tips_balances.get(&key) {
+					self.tips_balances.insert(&key, &tips_balance.add_balance(amount));
+				}
+			},
+		}
+	}
+}
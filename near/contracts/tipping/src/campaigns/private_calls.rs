@@ -0,0 +1,14 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	#[private]
+	pub fn resolve_refund_campaign_contribution(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		sender: AccountId,
+		amount: Balance,
+	) {
+		self.internal_resolve_refund_campaign_contribution(tips_balance_info, sender, amount);
+	}
+}
@@ -0,0 +1,533 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod campaigns_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env, RuntimeFeesConfig, VMConfig,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn send_tip_counts_towards_campaign() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::AllOrNothing,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		let progress = contract.get_campaign(tips_balance_info.clone()).unwrap();
+		let contribution = contract.get_campaign_contribution(tips_balance_info, accounts(3));
+
+		assert_eq!(progress.status, CampaignStatus::Active);
+		assert_eq!(progress.progress, 6000);
+		assert_eq!(progress.campaign.get_raised(), 60);
+		assert_eq!(progress.symbol, "NEAR".to_string());
+		assert_eq!(contribution.0, 60);
+	}
+
+	#[test]
+	#[should_panic(expected = "NothingToClaimed")]
+	fn cant_claim_active_campaign() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::AllOrNothing,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.claim_tip(tips_balance_info);
+	}
+
+	#[test]
+	fn campaign_succeeds_when_goal_reached() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::AllOrNothing,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// accounts(1) contributes the remaining 40
+		testing_env!(context
+			.signer_account_id(accounts(1))
+			.predecessor_account_id(accounts(1))
+			.attached_deposit(40)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		let progress = contract.get_campaign(tips_balance_info.clone()).unwrap();
+
+		assert_eq!(progress.status, CampaignStatus::Succeeded);
+		assert_eq!(progress.progress, 10000);
+		assert_eq!(contract.campaign_locked_amount(&tips_balance_info.key()), 0);
+	}
+
+	#[test]
+	fn keep_what_you_raise_succeeds_after_deadline() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::KeepWhatYouRaise,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		testing_env!(context.block_timestamp(1001).build());
+		let progress = contract.get_campaign(tips_balance_info).unwrap();
+
+		assert_eq!(progress.status, CampaignStatus::Succeeded);
+		assert_eq!(progress.campaign.get_raised(), 60);
+	}
+
+	#[test]
+	fn refund_failed_campaign_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::AllOrNothing,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// accounts(3) takes the contribution back after the deadline
+		testing_env!(context.block_timestamp(1001).attached_deposit(1).build());
+		contract.refund_campaign_contribution(tips_balance_info.clone());
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info.clone()).unwrap();
+		let progress = contract.get_campaign(tips_balance_info.clone()).unwrap();
+		let contribution =
+			contract.get_campaign_contribution(tips_balance_info.clone(), accounts(3));
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 0);
+		assert_eq!(progress.status, CampaignStatus::Failed);
+		assert_eq!(progress.campaign.get_refunded(), 60);
+		assert_eq!(contribution.0, 0);
+
+		// Failed campaign is settled, so a new round can start
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		let round = contract.create_campaign(
+			tips_balance_info,
+			U128(100),
+			2000,
+			CampaignMode::AllOrNothing,
+		);
+
+		assert_eq!(round, 1);
+	}
+
+	#[test]
+	fn failed_refund_restores_contribution() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::AllOrNothing,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// accounts(3) takes the contribution back after the deadline
+		testing_env!(context.block_timestamp(1001).attached_deposit(1).build());
+		contract.refund_campaign_contribution(tips_balance_info.clone());
+
+		// Transfer fails
+		testing_env!(
+			context.predecessor_account_id(accounts(0)).build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![PromiseResult::Failed],
+		);
+		contract.resolve_refund_campaign_contribution(tips_balance_info.clone(), accounts(3), 60);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info.clone()).unwrap();
+		let progress = contract.get_campaign(tips_balance_info.clone()).unwrap();
+		let contribution = contract.get_campaign_contribution(tips_balance_info, accounts(3));
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 60);
+		assert_eq!(progress.campaign.get_refunded(), 0);
+		assert_eq!(contribution.0, 60);
+	}
+
+	#[test]
+	#[should_panic(expected = "CampaignNotFailed")]
+	fn cant_refund_active_campaign() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::AllOrNothing,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		testing_env!(context.attached_deposit(1).build());
+		contract.refund_campaign_contribution(tips_balance_info);
+	}
+
+	#[test]
+	#[should_panic(expected = "CampaignInProgress")]
+	fn cant_create_campaign_while_active() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::AllOrNothing,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(tips_balance_info, U128(50), 2000, CampaignMode::AllOrNothing);
+	}
+
+	#[test]
+	#[should_panic(expected = "Unauthorized")]
+	fn cant_create_campaign_when_not_reference_owner() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// Test
+		testing_env!(context.attached_deposit(1).build());
+		contract.create_campaign(tips_balance_info, U128(100), 1000, CampaignMode::AllOrNothing);
+	}
+
+	#[test]
+	fn cancel_tip_reverses_campaign_contribution() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::AllOrNothing,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// accounts(3) cancels the contribution
+		let tip_records = contract.get_tip_records_by_sender(accounts(3), None, None);
+		let tip_id = tip_records.data[0].tip_record.get_id();
+
+		testing_env!(context.attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+
+		// Test
+		let progress = contract.get_campaign(tips_balance_info.clone()).unwrap();
+		let contribution = contract.get_campaign_contribution(tips_balance_info, accounts(3));
+
+		assert_eq!(progress.campaign.get_raised(), 0);
+		assert_eq!(contribution.0, 0);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipAlreadyRefunded")]
+	fn cant_cancel_refunded_campaign_contribution() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// accounts(2) starts a campaign of 100 until timestamp 1000
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.create_campaign(
+			tips_balance_info.clone(),
+			U128(100),
+			1000,
+			CampaignMode::AllOrNothing,
+		);
+
+		// accounts(3) contributes 60
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(60)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// accounts(3) takes the contribution back after the deadline
+		testing_env!(context.block_timestamp(1001).attached_deposit(1).build());
+		contract.refund_campaign_contribution(tips_balance_info.clone());
+
+		// accounts(1) tips after the campaign failed
+		testing_env!(context
+			.signer_account_id(accounts(1))
+			.predecessor_account_id(accounts(1))
+			.attached_deposit(100)
+			.build());
+		contract.send_tip(tips_balance_info, None);
+
+		// accounts(3) cancels the refunded tip
+		let tip_records = contract.get_tip_records_by_sender(accounts(3), None, None);
+		let tip_id = tip_records.data[0].tip_record.get_id();
+
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.block_timestamp(1002)
+			.attached_deposit(1)
+			.build());
+		contract.cancel_tip(tip_id);
+	}
+}
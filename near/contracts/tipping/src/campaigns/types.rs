@@ -0,0 +1,131 @@
+use crate::*;
+
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum CampaignMode {
+	AllOrNothing,
+	KeepWhatYouRaise,
+}
+
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum CampaignStatus {
+	Active,
+	Succeeded,
+	Failed,
+}
+
+/// Crowdfunding goal on a `TipsBalance`. Tips sent before the deadline are counted per sender and
+/// locked from claims until the campaign succeeds. The round is bumped by every new campaign on
+/// the same balance, which invalidates the contributions to earlier ones.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Campaign {
+	round: u64,
+	goal: U128,
+	deadline: Timestamp,
+	mode: CampaignMode,
+	raised: U128,
+	refunded: U128,
+	started_at: Timestamp,
+}
+impl Campaign {
+	pub fn new(round: u64, goal: Balance, deadline: Timestamp, mode: CampaignMode) -> Self {
+		Self {
+			round,
+			goal: U128(goal),
+			deadline,
+			mode,
+			raised: U128(Zero::zero()),
+			refunded: U128(Zero::zero()),
+			started_at: env::block_timestamp(),
+		}
+	}
+
+	pub fn get_round(&self) -> u64 {
+		self.round
+	}
+
+	pub fn get_goal(&self) -> Balance {
+		self.goal.0
+	}
+
+	pub fn get_deadline(&self) -> Timestamp {
+		self.deadline
+	}
+
+	pub fn get_mode(&self) -> CampaignMode {
+		self.mode
+	}
+
+	pub fn get_raised(&self) -> Balance {
+		self.raised.0
+	}
+
+	pub fn get_refunded(&self) -> Balance {
+		self.refunded.0
+	}
+
+	pub fn status(&self, now: Timestamp) -> CampaignStatus {
+		if self.get_raised() >= self.get_goal() {
+			return CampaignStatus::Succeeded
+		}
+
+		if now <= self.deadline {
+			return CampaignStatus::Active
+		}
+
+		match self.mode {
+			CampaignMode::AllOrNothing => CampaignStatus::Failed,
+			CampaignMode::KeepWhatYouRaise => CampaignStatus::Succeeded,
+		}
+	}
+
+	/// Amount of the balance that cannot be claimed yet.
+	pub fn locked_amount(&self, now: Timestamp) -> Balance {
+		match self.status(now) {
+			CampaignStatus::Succeeded => Zero::zero(),
+			_ => self.get_raised() - self.get_refunded(),
+		}
+	}
+
+	/// A new campaign can start once the previous one succeeded or refunded everything.
+	pub fn is_settled(&self, now: Timestamp) -> bool {
+		self.locked_amount(now).is_zero() && self.status(now) != CampaignStatus::Active
+	}
+
+	pub fn add_raised(mut self, amount: Balance) -> Self {
+		self.raised = U128(self.get_raised() + amount);
+		self
+	}
+
+	pub fn remove_raised(mut self, amount: Balance) -> Self {
+		self.raised = U128(self.get_raised() - amount);
+		self
+	}
+
+	pub fn add_refunded(mut self, amount: Balance) -> Self {
+		self.refunded = U128(self.get_refunded() + amount);
+		self
+	}
+
+	pub fn remove_refunded(mut self, amount: Balance) -> Self {
+		self.refunded = U128(self.get_refunded() - amount);
+		self
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CampaignProgress {
+	pub campaign: Campaign,
+	pub status: CampaignStatus,
+	pub progress: u16, // basis points of the goal, at most 10000
+	pub symbol: String,
+	pub formatted_goal: String,
+	pub formatted_raised: String,
+}
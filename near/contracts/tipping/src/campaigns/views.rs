@@ -0,0 +1,31 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_campaign(&self, tips_balance_info: TipsBalanceInfo) -> Option<CampaignProgress> {
+		let campaign = self.campaigns.get(&tips_balance_info.key())?;
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let ft_info = self.ft_identifiers.get(&ft_identifier)?;
+		let decimals = ft_info.get_decimals() as usize;
+		let raised = campaign.get_raised();
+		let goal = campaign.get_goal();
+		let progress = self.proportion(raised, TOTAL_SPLIT_WEIGHT as Balance, goal);
+
+		Some(CampaignProgress {
+			status: campaign.status(env::block_timestamp()),
+			progress: min(progress, TOTAL_SPLIT_WEIGHT as Balance) as u16,
+			symbol: ft_info.get_symbol().to_string(),
+			formatted_goal: self.formatted_balance(&goal.to_string(), decimals),
+			formatted_raised: self.formatted_balance(&raised.to_string(), decimals),
+			campaign,
+		})
+	}
+
+	pub fn get_campaign_contribution(
+		&self,
+		tips_balance_info: TipsBalanceInfo,
+		sender: AccountId,
+	) -> U128 {
+		U128(self.campaign_contribution_of(&tips_balance_info.key(), &sender))
+	}
+}
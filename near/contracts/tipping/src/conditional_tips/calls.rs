@@ -0,0 +1,79 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Escrows a tip from the deposit of the caller until the condition is met, or refunds it once
+	/// the deadline passed.
+	#[payable]
+	pub fn create_conditional_tip(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		amount: U128,
+		condition: TipCondition,
+		deadline: Timestamp,
+		memo: Option<String>,
+	) -> ConditionalTipId {
+		let sender = env::predecessor_account_id();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let mut conditional_tip_ids = self.conditional_tip_ids_of(&sender);
+		let now = env::block_timestamp();
+
+		assert!(amount.0 > 0, "NothingToTip");
+		assert!(
+			conditional_tip_ids.len() < MAX_CONDITIONAL_TIPS_PER_SENDER,
+			"TooManyConditionalTips"
+		);
+		assert!(self.ft_identifiers.get(&ft_identifier).is_some(), "TokenNotFound");
+		self.assert_not_paused(PauseFeature::Tipping, Some(&ft_identifier));
+		self.assert_not_blocked(&sender, &tips_balance_info);
+		self.assert_memo(&memo);
+
+		assert!(deadline > now, "InvalidDeadline");
+
+		if let TipCondition::ReleaseAt { timestamp } = condition {
+			assert!(timestamp > now, "InvalidReleaseTime");
+			assert!(deadline >= timestamp, "InvalidDeadline");
+		}
+
+		self.add_attached_deposit(&sender);
+
+		self.remove_deposit(&sender, &ft_identifier, amount.0);
+
+		let id = self.next_conditional_tip_id;
+		let conditional_tip = ConditionalTip::new(
+			id,
+			&sender,
+			&tips_balance_info,
+			amount.0,
+			condition,
+			deadline,
+			memo,
+		);
+
+		conditional_tip_ids.push(id);
+
+		self.next_conditional_tip_id += 1;
+		self.conditional_tips.insert(&id, &conditional_tip);
+		self.conditional_tip_ids.insert(&sender, &conditional_tip_ids);
+
+		id
+	}
+
+	/// Releases or refunds the given conditional tips when they are due, callable by anyone.
+	/// Returns the number of tips settled.
+	pub fn trigger_conditional_tips(&mut self, conditional_tip_ids: Vec<ConditionalTipId>) -> u64 {
+		assert!(conditional_tip_ids.len() <= MAX_TRIGGERED_TIPS, "TooManyConditionalTips");
+
+		let mut settled_count = 0;
+
+		for id in conditional_tip_ids {
+			if let Some(conditional_tip) = self.conditional_tips.get(&id) {
+				if self.trigger_conditional_tip(conditional_tip) {
+					settled_count += 1;
+				}
+			}
+		}
+
+		settled_count
+	}
+}
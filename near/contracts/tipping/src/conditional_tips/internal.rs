@@ -0,0 +1,89 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn conditional_tip_ids_of(&self, sender: &AccountId) -> Vec<ConditionalTipId> {
+		self.conditional_tip_ids.get(sender).unwrap_or_default()
+	}
+
+	pub(crate) fn remove_conditional_tip(&mut self, conditional_tip: &ConditionalTip) {
+		let id = conditional_tip.get_id();
+		let sender = conditional_tip.get_sender();
+		let mut conditional_tip_ids = self.conditional_tip_ids_of(sender);
+
+		conditional_tip_ids.retain(|conditional_tip_id| *conditional_tip_id != id);
+
+		if conditional_tip_ids.is_empty() {
+			self.conditional_tip_ids.remove(sender);
+		} else {
+			self.conditional_tip_ids.insert(sender, &conditional_tip_ids);
+		}
+
+		self.conditional_tips.remove(&id);
+	}
+
+	pub(crate) fn is_condition_met(&self, conditional_tip: &ConditionalTip) -> bool {
+		let tips_balance_info = conditional_tip.get_tips_balance_info();
+
+		match conditional_tip.get_condition() {
+			TipCondition::ReleaseAt { timestamp } => env::block_timestamp() >= *timestamp,
+			TipCondition::Claimed => self.reference_account_id(tips_balance_info).is_some(),
+			TipCondition::TotalReached { amount } =>
+				match self.tips_balances.get(&tips_balance_info.key()) {
+					Some(tips_balance) => tips_balance.get_total_amount() >= amount.0,
+					None => false,
+				},
+		}
+	}
+
+	/// Releases a conditional tip whose condition is met into its `TipsBalance`, or refunds an
+	/// expired one to the deposit of the sender. Returns whether it was settled.
+	pub(crate) fn trigger_conditional_tip(&mut self, conditional_tip: ConditionalTip) -> bool {
+		let id = conditional_tip.get_id();
+		let sender = conditional_tip.get_sender().clone();
+		let tips_balance_info = conditional_tip.get_tips_balance_info().clone();
+		let amount = conditional_tip.get_amount();
+
+		if self.is_condition_met(&conditional_tip) {
+			if let Some(reason) = self.tip_rejection_reason(&sender, &tips_balance_info, amount) {
+				log!("{}: conditional tip {} is not released", reason, id);
+			} else {
+				let memo = conditional_tip.get_memo().clone();
+				let reference = tips_balance_info.reference();
+				let ft_identifier = tips_balance_info.get_ft_identifier();
+				let (symbol, formatted) = self.formatted_amount_of(ft_identifier, amount);
+
+				self.remove_conditional_tip(&conditional_tip);
+				self.create_tips_balance(
+					&sender,
+					&tips_balance_info,
+					&amount,
+					memo,
+					TipKind::Settled,
+				);
+
+				log!("{} tipped {} {} to {}", sender, formatted, symbol, reference);
+
+				return true
+			}
+		}
+
+		if conditional_tip.is_expired(env::block_timestamp()) {
+			let ft_identifier = tips_balance_info.get_ft_identifier();
+
+			if let Some(reason) = self.paused_reason(PauseFeature::Claiming, Some(ft_identifier)) {
+				log!("{}: conditional tip {} is not refunded", reason, id);
+
+				return false
+			}
+
+			self.remove_conditional_tip(&conditional_tip);
+			self.add_deposit(&sender, ft_identifier, amount);
+
+			log!("Conditional tip {} refunded to {}", id, sender);
+
+			return true
+		}
+
+		false
+	}
+}
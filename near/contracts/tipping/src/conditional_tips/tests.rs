@@ -0,0 +1,183 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod conditional_tips_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn scheduled_tip_is_released_at_timestamp() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) escrows a tip to post_1
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		let id = contract.create_conditional_tip(
+			tips_balance_info.clone(),
+			U128(100),
+			TipCondition::ReleaseAt { timestamp: NANOSECONDS_PER_DAY },
+			2 * NANOSECONDS_PER_DAY,
+			None,
+		);
+
+		// Trigger before and at the release time
+		testing_env!(context
+			.predecessor_account_id(accounts(5))
+			.attached_deposit(0)
+			.block_timestamp(NANOSECONDS_PER_DAY - 1)
+			.build());
+		let early_count = contract.trigger_conditional_tips(vec![id]);
+
+		testing_env!(context.block_timestamp(NANOSECONDS_PER_DAY).build());
+		let count = contract.trigger_conditional_tips(vec![id]);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(early_count, 0);
+		assert_eq!(count, 1);
+		assert_eq!(tips_balance.tips_balance.get_amount(), 100);
+		assert!(contract.get_conditional_tip(id).is_none());
+	}
+
+	#[test]
+	fn conditional_tip_is_released_when_reference_claimed() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) escrows a tip to post_1
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		let id = contract.create_conditional_tip(
+			tips_balance_info.clone(),
+			U128(100),
+			TipCondition::Claimed,
+			NANOSECONDS_PER_DAY,
+			None,
+		);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// Trigger
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(0).build());
+		contract.trigger_conditional_tips(vec![id]);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 100);
+	}
+
+	#[test]
+	fn conditional_tip_is_refunded_after_deadline() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) escrows a tip to post_1
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		let id = contract.create_conditional_tip(
+			tips_balance_info.clone(),
+			U128(100),
+			TipCondition::TotalReached { amount: U128(1000) },
+			NANOSECONDS_PER_DAY,
+			None,
+		);
+
+		assert_eq!(contract.get_conditional_tips(None, None).data.len(), 1);
+
+		// Trigger after the deadline
+		testing_env!(context
+			.predecessor_account_id(accounts(5))
+			.attached_deposit(0)
+			.block_timestamp(NANOSECONDS_PER_DAY + 1)
+			.build());
+		contract.trigger_conditional_tips(vec![id]);
+
+		// Test
+		assert!(contract.get_tips_balance(tips_balance_info).is_none());
+		assert!(contract.get_conditional_tips_by_sender(accounts(3)).is_empty());
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(100));
+	}
+
+	#[test]
+	#[should_panic(expected = "InvalidDeadline")]
+	fn cant_create_scheduled_tip_with_deadline_before_release() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) escrows a tip to post_1
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.create_conditional_tip(
+			tips_balance_info.clone(),
+			U128(100),
+			TipCondition::ReleaseAt { timestamp: NANOSECONDS_PER_DAY },
+			NANOSECONDS_PER_DAY - 1,
+			None,
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipNotCancellable")]
+	fn cant_cancel_released_conditional_tip() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) escrows a tip to post_1
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		let id = contract.create_conditional_tip(
+			tips_balance_info.clone(),
+			U128(100),
+			TipCondition::ReleaseAt { timestamp: NANOSECONDS_PER_DAY },
+			2 * NANOSECONDS_PER_DAY,
+			None,
+		);
+
+		// Trigger at the release time
+		testing_env!(context
+			.predecessor_account_id(accounts(5))
+			.attached_deposit(0)
+			.block_timestamp(NANOSECONDS_PER_DAY)
+			.build());
+		contract.trigger_conditional_tips(vec![id]);
+
+		// accounts(3) cancels the released tip
+		let tip_records = contract.get_tip_records_by_sender(accounts(3), None, None);
+		let tip_id = tip_records.data[0].tip_record.get_id();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+	}
+}
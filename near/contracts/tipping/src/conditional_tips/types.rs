@@ -0,0 +1,91 @@
+use crate::*;
+
+pub type ConditionalTipId = u64;
+
+pub const MAX_CONDITIONAL_TIPS_PER_SENDER: usize = 20;
+pub const MAX_TRIGGERED_TIPS: usize = 20;
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde", tag = "type", rename_all = "snake_case")]
+pub enum TipCondition {
+	ReleaseAt { timestamp: Timestamp },
+	Claimed,
+	TotalReached { amount: U128 },
+}
+
+/// Tip held in escrow until its condition is met, or refunded once its deadline passes.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConditionalTip {
+	id: ConditionalTipId,
+	sender: AccountId,
+	tips_balance_info: TipsBalanceInfo,
+	amount: U128,
+	condition: TipCondition,
+	deadline: Timestamp,
+	memo: Option<String>,
+	created_at: Timestamp,
+}
+impl ConditionalTip {
+	pub fn new(
+		id: ConditionalTipId,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+		condition: TipCondition,
+		deadline: Timestamp,
+		memo: Option<String>,
+	) -> Self {
+		Self {
+			id,
+			sender: sender.clone(),
+			tips_balance_info: tips_balance_info.clone(),
+			amount: U128(amount),
+			condition,
+			deadline,
+			memo,
+			created_at: env::block_timestamp(),
+		}
+	}
+
+	pub fn get_id(&self) -> ConditionalTipId {
+		self.id
+	}
+
+	pub fn get_sender(&self) -> &AccountId {
+		&self.sender
+	}
+
+	pub fn get_tips_balance_info(&self) -> &TipsBalanceInfo {
+		&self.tips_balance_info
+	}
+
+	pub fn get_amount(&self) -> Balance {
+		self.amount.0
+	}
+
+	pub fn get_condition(&self) -> &TipCondition {
+		&self.condition
+	}
+
+	pub fn get_deadline(&self) -> Timestamp {
+		self.deadline
+	}
+
+	pub fn get_memo(&self) -> &Option<String> {
+		&self.memo
+	}
+
+	pub fn is_expired(&self, now: Timestamp) -> bool {
+		now > self.deadline
+	}
+}
+
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConditionalTipWithPagination {
+	pub data: Vec<ConditionalTip>,
+	pub meta: Metadata,
+}
@@ -0,0 +1,45 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_conditional_tip(
+		&self,
+		conditional_tip_id: ConditionalTipId,
+	) -> Option<ConditionalTip> {
+		self.conditional_tips.get(&conditional_tip_id)
+	}
+
+	pub fn get_conditional_tips_by_sender(&self, sender: AccountId) -> Vec<ConditionalTip> {
+		self.conditional_tip_ids_of(&sender)
+			.iter()
+			.filter_map(|id| self.conditional_tips.get(id))
+			.collect()
+	}
+
+	pub fn get_conditional_tips(
+		&self,
+		page_number: Option<u64>,
+		page_limit: Option<u64>,
+	) -> ConditionalTipWithPagination {
+		if self.conditional_tips.is_empty() {
+			return ConditionalTipWithPagination::default()
+		}
+
+		let total_item = self.conditional_tips.len();
+		let meta = self.metadata(total_item, page_number, page_limit);
+
+		if meta.current_page.is_none() {
+			return ConditionalTipWithPagination::default()
+		}
+
+		let page_number = meta.current_page.unwrap();
+		let page_limit = meta.items_per_page;
+		let from_index: u64 = (page_number - 1) * page_limit;
+		let values = self.conditional_tips.values_as_vector();
+		let data = (from_index..min(from_index + page_limit, total_item))
+			.filter_map(|index| values.get(index))
+			.collect();
+
+		ConditionalTipWithPagination { data, meta }
+	}
+}
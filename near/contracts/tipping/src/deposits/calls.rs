@@ -0,0 +1,54 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Adds the attached deposit to the NEAR deposit of the caller.
+	#[payable]
+	pub fn deposit(&mut self) -> U128 {
+		let amount = env::attached_deposit();
+		let account_id = env::predecessor_account_id();
+
+		assert!(amount > 0, "NothingToDeposit");
+		self.assert_not_paused(PauseFeature::Tipping, Some("native"));
+
+		self.add_deposit(&account_id, "native", amount);
+		self.deposit_log(&account_id, "native", amount);
+
+		U128(self.deposit_of(&account_id, "native"))
+	}
+
+	pub fn tip_from_balance(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		amount: U128,
+		memo: Option<String>,
+	) -> TipId {
+		let sender = env::predecessor_account_id();
+
+		self.internal_tip_from_balance(&sender, &tips_balance_info, amount.0, memo, TipKind::Direct)
+	}
+
+	/// Withdraws an amount of the deposit of the caller in a token, the whole deposit by default.
+	#[payable]
+	pub fn withdraw_deposit(&mut self, ft_identifier: FtIdentifier, amount: Option<U128>) {
+		assert_one_yocto();
+		self.assert_not_paused(PauseFeature::Claiming, Some(&ft_identifier));
+
+		let account_id = env::predecessor_account_id();
+		let amount = match amount {
+			Some(amount) => amount.0,
+			None => self.deposit_of(&account_id, &ft_identifier),
+		};
+
+		assert!(amount > 0, "NothingToWithdraw");
+
+		self.remove_deposit(&account_id, &ft_identifier, amount);
+		self.transfer(&account_id, &ft_identifier, amount).then(
+			Self::ext(env::current_account_id()).resolve_withdraw_deposit(
+				account_id,
+				ft_identifier,
+				amount,
+			),
+		);
+	}
+}
@@ -0,0 +1,131 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn deposit_of(&self, account_id: &AccountId, ft_identifier: &str) -> Balance {
+		let key = (account_id.clone(), ft_identifier.to_string());
+
+		self.deposits.get(&key).unwrap_or_default()
+	}
+
+	pub(crate) fn add_deposit(
+		&mut self,
+		account_id: &AccountId,
+		ft_identifier: &str,
+		amount: Balance,
+	) {
+		let key = (account_id.clone(), ft_identifier.to_string());
+		let deposit = self.deposit_of(account_id, ft_identifier);
+
+		self.deposits.insert(&key, &(deposit + amount));
+	}
+
+	pub(crate) fn remove_deposit(
+		&mut self,
+		account_id: &AccountId,
+		ft_identifier: &str,
+		amount: Balance,
+	) {
+		let key = (account_id.clone(), ft_identifier.to_string());
+		let deposit = self.deposit_of(account_id, ft_identifier);
+
+		assert!(deposit >= amount, "InsufficientDeposit");
+
+		if deposit == amount {
+			self.deposits.remove(&key);
+		} else {
+			self.deposits.insert(&key, &(deposit - amount));
+		}
+	}
+
+	/// Adds the NEAR attached to a call to the deposit of the caller, for the calls that are paid
+	/// from the deposit.
+	pub(crate) fn add_attached_deposit(&mut self, account_id: &AccountId) {
+		let amount = env::attached_deposit();
+
+		if amount > 0 {
+			self.add_deposit(account_id, "native", amount);
+			self.deposit_log(account_id, "native", amount);
+		}
+	}
+
+	/// Moves an amount from the deposit of the sender into a `TipsBalance`, no transfer is made.
+	pub(crate) fn internal_tip_from_balance(
+		&mut self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+		memo: Option<String>,
+		kind: TipKind,
+	) -> TipId {
+		let ft_identifier = tips_balance_info.get_ft_identifier();
+		let ft_info = self.ft_identifiers.get(&ft_identifier.to_string()).expect("TokenNotFound");
+
+		assert!(amount > 0, "NothingToTip");
+		self.assert_tip_allowed(sender, tips_balance_info, amount);
+		self.assert_memo(&memo);
+		self.remove_deposit(sender, ft_identifier, amount);
+
+		let symbol = ft_info.get_symbol();
+		let decimals = ft_info.get_decimals() as usize;
+		let reference = tips_balance_info.reference();
+		let amount_str = amount.to_string();
+		let tip_id = self.create_tips_balance(sender, tips_balance_info, &amount, memo, kind);
+
+		self.tip_log(sender, &amount_str, symbol, decimals, Some(reference));
+
+		tip_id
+	}
+
+	pub(crate) fn internal_resolve_deposit(
+		&mut self,
+		sender: AccountId,
+		ft_identifier: FtIdentifier,
+		amount: Balance,
+	) -> U128 {
+		require!(env::promise_results_count() == 1);
+
+		if let Some(reason) = self.paused_reason(PauseFeature::Tipping, Some(&ft_identifier)) {
+			log!("{}: refunding {} to {}", reason, amount, sender);
+			return U128(amount)
+		}
+
+		if let PromiseResult::Successful(val) = env::promise_result(0) {
+			if let Ok(ft_metadata) = from_slice::<FungibleTokenMetadata>(&val) {
+				let symbol = ft_metadata.get_symbol();
+				let decimals = ft_metadata.get_decimals();
+
+				self.create_ft_identifier(&ft_identifier, symbol, decimals);
+				self.add_deposit(&sender, &ft_identifier, amount);
+				self.deposit_log(&sender, &ft_identifier, amount);
+
+				return U128(0)
+			}
+		}
+
+		U128(amount)
+	}
+
+	pub(crate) fn internal_resolve_withdraw_deposit(
+		&mut self,
+		account_id: AccountId,
+		ft_identifier: FtIdentifier,
+		amount: Balance,
+	) {
+		require!(env::promise_results_count() == 1);
+
+		let (symbol, formatted) = self.formatted_amount_of(&ft_identifier, amount);
+
+		match env::promise_result(0) {
+			PromiseResult::Successful(_) => {
+				log!("{} withdrew {} {} from deposit", account_id, formatted, symbol);
+			},
+			_ => self.add_deposit(&account_id, &ft_identifier, amount),
+		}
+	}
+
+	pub(crate) fn deposit_log(&self, account_id: &AccountId, ft_identifier: &str, amount: Balance) {
+		let (symbol, formatted) = self.formatted_amount_of(ft_identifier, amount);
+
+		log!("{} deposited {} {}", account_id, formatted, symbol);
+	}
+}
@@ -0,0 +1,38 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	#[private]
+	pub fn deposit_by_ft(&mut self, sender: AccountId, amount: Balance) -> PromiseOrValue<U128> {
+		let ft_identifier = env::predecessor_account_id();
+
+		ft_contract::ext(ft_identifier.clone())
+			.ft_metadata()
+			.then(Self::ext(env::current_account_id()).resolve_deposit(
+				sender,
+				ft_identifier.to_string(),
+				amount,
+			))
+			.into()
+	}
+
+	#[private]
+	pub fn resolve_deposit(
+		&mut self,
+		sender: AccountId,
+		ft_identifier: FtIdentifier,
+		amount: Balance,
+	) -> U128 {
+		self.internal_resolve_deposit(sender, ft_identifier, amount)
+	}
+
+	#[private]
+	pub fn resolve_withdraw_deposit(
+		&mut self,
+		account_id: AccountId,
+		ft_identifier: FtIdentifier,
+		amount: Balance,
+	) {
+		self.internal_resolve_withdraw_deposit(account_id, ft_identifier, amount);
+	}
+}
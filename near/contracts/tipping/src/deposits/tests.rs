@@ -0,0 +1,132 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod deposits_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env, RuntimeFeesConfig, VMConfig,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn tip_from_balance_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Deposit and tip from the deposit
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.tip_from_balance(tips_balance_info.clone(), U128(30), None);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+		let deposits = contract.get_deposits(accounts(3));
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 30);
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(70));
+		assert_eq!(deposits.len(), 1);
+		assert_eq!(deposits[0].symbol, String::from("NEAR"));
+	}
+
+	#[test]
+	fn cancel_tip_from_balance_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Deposit and tip from the deposit
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		let tip_id = contract.tip_from_balance(tips_balance_info.clone(), U128(30), None);
+
+		// Cancel the tip
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 0);
+		assert!(contract.get_tip_record(tip_id).unwrap().tip_record.is_cancelled());
+	}
+
+	#[test]
+	#[should_panic(expected = "InsufficientDeposit")]
+	fn cant_tip_from_balance_more_than_deposit() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Deposit and tip from the deposit
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.tip_from_balance(tips_balance_info, U128(101), None);
+	}
+
+	#[test]
+	fn failed_withdraw_deposit_is_restored() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Deposit and withdraw
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.withdraw_deposit(String::from("native"), None);
+
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(0));
+
+		// Resolve the failed transfer
+		testing_env!(
+			context.predecessor_account_id(accounts(0)).build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![PromiseResult::Failed],
+		);
+		contract.resolve_withdraw_deposit(accounts(3), String::from("native"), 100);
+
+		// Test
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(100));
+	}
+
+	#[test]
+	fn tip_message_supports_deposit() {
+		let deposit = from_str::<TipMessage>(r#"{"version":2,"type":"deposit"}"#).unwrap();
+
+		assert_eq!(
+			deposit,
+			TipMessage::Versioned(VersionedTipMessage { version: 2, action: TipAction::Deposit })
+		);
+	}
+}
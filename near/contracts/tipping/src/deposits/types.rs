@@ -0,0 +1,12 @@
+use crate::*;
+
+pub type DepositKey = (AccountId, FtIdentifier);
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DepositWithFormattedAmount {
+	pub ft_identifier: FtIdentifier,
+	pub amount: U128,
+	pub symbol: String,
+	pub formatted_amount: String,
+}
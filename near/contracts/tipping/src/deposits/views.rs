@@ -0,0 +1,35 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_deposit(&self, account_id: AccountId, ft_identifier: FtIdentifier) -> U128 {
+		U128(self.deposit_of(&account_id, &ft_identifier))
+	}
+
+	/// Returns every non-empty deposit of an account.
+	pub fn get_deposits(&self, account_id: AccountId) -> Vec<DepositWithFormattedAmount> {
+		let values = self.ft_identifiers.values_as_vector();
+
+		(0..self.ft_identifiers.len())
+			.filter_map(|index| {
+				let ft_info = values.get(index)?;
+				let ft_identifier = ft_info.get_ft_identifier();
+				let amount = self.deposit_of(&account_id, ft_identifier);
+
+				if amount.is_zero() {
+					return None
+				}
+
+				let decimals = ft_info.get_decimals() as usize;
+				let formatted_amount = self.formatted_balance(&amount.to_string(), decimals);
+
+				Some(DepositWithFormattedAmount {
+					ft_identifier: ft_identifier.to_string(),
+					amount: U128(amount),
+					symbol: ft_info.get_symbol().to_string(),
+					formatted_amount,
+				})
+			})
+			.collect()
+	}
+}
@@ -21,6 +21,7 @@ impl FungibleTokenMetadata {
 	}
 }
 
+#[allow(dead_code)]
 #[ext_contract(ft_contract)]
 trait FtContract {
 	fn ft_metadata() -> FungibleTokenMetadata;
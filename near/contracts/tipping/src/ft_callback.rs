@@ -1,5 +1,6 @@
 use crate::*;
 
+#[allow(dead_code)]
 trait FungibleTokenReceiver {
 	fn ft_on_transfer(
 		&mut self,
@@ -17,8 +18,40 @@ impl FungibleTokenReceiver for Tipping {
 		amount: U128,
 		msg: String,
 	) -> PromiseOrValue<U128> {
-		let tips_balance_info = from_str::<TipsBalanceInfo>(&msg).expect("InvalidArgument");
+		let tip_message = from_str::<TipMessage>(&msg).expect("InvalidArgument");
 
-		self.send_tip_by_ft(tips_balance_info, sender_id, amount.into())
+		match tip_message {
+			TipMessage::Single(SingleTipMessage { tips_balance_info, memo }) => {
+				self.assert_memo(&memo);
+
+				let ft_identifier = env::predecessor_account_id();
+				let reason = if tips_balance_info.get_ft_identifier() != ft_identifier.as_str() {
+					Some("InvalidFtIdentifier")
+				} else {
+					self.tip_rejection_reason(&sender_id, &tips_balance_info, amount.into())
+				};
+
+				if let Some(reason) = reason {
+					log!("{}: refunding {} to {}", reason, amount.0, sender_id);
+					return PromiseOrValue::Value(amount)
+				}
+
+				self.send_tip_by_ft(tips_balance_info, sender_id, amount.into(), memo)
+			},
+			TipMessage::Versioned(VersionedTipMessage { version, action }) => {
+				assert!(version == TIP_MESSAGE_VERSION, "UnsupportedMessageVersion");
+
+				match action {
+					TipAction::Batch { tips, memo } =>
+						self.send_tips_by_ft(tips, sender_id, amount.into(), memo),
+					TipAction::Split { shares, memo } => {
+						let tips = self.split_tips(&shares, amount.into());
+
+						self.send_tips_by_ft(tips, sender_id, amount.into(), memo)
+					},
+					TipAction::Deposit => self.deposit_by_ft(sender_id, amount.into()),
+				}
+			},
+		}
 	}
 }
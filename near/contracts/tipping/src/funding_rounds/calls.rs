@@ -0,0 +1,85 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Moves an amount of the deposit of the caller into the pool of a new round. Only the server
+	/// of the references can put them in a round.
+	#[payable]
+	pub fn create_funding_round(
+		&mut self,
+		ft_identifier: FtIdentifier,
+		pool: U128,
+		references: Vec<TipsBalanceInfo>,
+		starts_at: Option<Timestamp>,
+		ends_at: Timestamp,
+	) -> FundingRoundId {
+		let creator = env::predecessor_account_id();
+		let now = env::block_timestamp();
+		let starts_at = starts_at.unwrap_or(now);
+		let keys: Vec<TipsBalanceKey> = references.iter().map(|info| info.key()).collect();
+
+		assert!(pool.0 > 0, "NothingToDeposit");
+		assert!(starts_at < ends_at && ends_at > now, "InvalidRoundWindow");
+		assert!(!references.is_empty(), "NothingToFund");
+		assert!(references.len() <= MAX_ROUND_REFERENCES, "TooManyReferences");
+		assert!(self.ft_identifiers.get(&ft_identifier).is_some(), "TokenNotFound");
+		self.assert_not_paused(PauseFeature::Tipping, Some(&ft_identifier));
+
+		for (index, tips_balance_info) in references.iter().enumerate() {
+			assert!(tips_balance_info.get_server_id() == &creator, "Unauthorized");
+			assert!(tips_balance_info.get_ft_identifier() == ft_identifier, "InvalidFtIdentifier");
+			assert!(!keys[..index].contains(&keys[index]), "DuplicateReference");
+			assert!(
+				self.funding_round_ids_of(&keys[index]).len() < MAX_ROUNDS_PER_REFERENCE,
+				"TooManyFundingRounds"
+			);
+		}
+
+		self.add_attached_deposit(&creator);
+
+		self.remove_deposit(&creator, &ft_identifier, pool.0);
+
+		let id = self.next_funding_round_id;
+		let funding_round =
+			FundingRound::new(id, &creator, &ft_identifier, pool.0, references, starts_at, ends_at);
+
+		for key in keys.iter() {
+			let mut ids = self.funding_round_ids_of(key);
+
+			ids.push(id);
+			self.funding_round_ids.insert(key, &ids);
+		}
+
+		self.next_funding_round_id += 1;
+		self.funding_rounds.insert(&id, &funding_round);
+
+		log!("{} created funding round {}", creator, id);
+
+		id
+	}
+
+	/// Finalizes a round that ended in chunks of at most `limit` references per call, anyone can
+	/// call it until the round is finalized.
+	pub fn finalize_funding_round(
+		&mut self,
+		funding_round_id: FundingRoundId,
+		limit: u64,
+	) -> FundingRoundPhase {
+		let funding_round =
+			self.funding_rounds.get(&funding_round_id).expect("FundingRoundNotExists");
+
+		assert!(env::block_timestamp() >= funding_round.get_ends_at(), "FundingRoundNotEnded");
+		assert!(
+			funding_round.get_phase() != FundingRoundPhase::Finalized,
+			"FundingRoundAlreadyFinalized"
+		);
+		self.assert_not_paused(PauseFeature::Tipping, Some(funding_round.get_ft_identifier()));
+
+		let funding_round = self.process_funding_round(funding_round, limit);
+		let phase = funding_round.get_phase();
+
+		self.funding_rounds.insert(&funding_round_id, &funding_round);
+
+		phase
+	}
+}
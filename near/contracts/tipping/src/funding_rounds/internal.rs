@@ -0,0 +1,238 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn funding_round_ids_of(&self, key: &TipsBalanceKey) -> Vec<FundingRoundId> {
+		self.funding_round_ids.get(key).unwrap_or_default()
+	}
+
+	pub(crate) fn remove_funding_round_id(&mut self, key: &TipsBalanceKey, id: FundingRoundId) {
+		let mut ids = self.funding_round_ids_of(key);
+
+		ids.retain(|funding_round_id| *funding_round_id != id);
+
+		if ids.is_empty() {
+			self.funding_round_ids.remove(key);
+		} else {
+			self.funding_round_ids.insert(key, &ids);
+		}
+	}
+
+	pub(crate) fn round_reference_of(
+		&self,
+		id: FundingRoundId,
+		key: &TipsBalanceKey,
+	) -> RoundReference {
+		self.round_references.get(&(id, key.clone())).unwrap_or_default()
+	}
+
+	/// Largest integer whose square is at most the value.
+	pub(crate) fn integer_sqrt(&self, value: Balance) -> Balance {
+		if value < 2 {
+			return value
+		}
+
+		let mut root = 1 << (128 - value.leading_zeros()).div_ceil(2);
+
+		loop {
+			let next = (root + value / root) / 2;
+
+			if next >= root {
+				return root
+			}
+
+			root = next;
+		}
+	}
+
+	/// Counts a tip towards every round the reference takes part in that is collecting, and keeps
+	/// the rounds on the record of the tip.
+	pub(crate) fn add_round_contribution(
+		&mut self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+		tip_id: TipId,
+	) {
+		let key = tips_balance_info.key();
+		let now = env::block_timestamp();
+		let mut funding_round_ids = Vec::new();
+
+		for id in self.funding_round_ids_of(&key) {
+			match self.funding_rounds.get(&id) {
+				Some(funding_round) if funding_round.is_collecting(now) => (),
+				_ => continue,
+			}
+
+			self.update_round_contribution(id, &key, sender, amount, true);
+			funding_round_ids.push(id);
+		}
+
+		if !funding_round_ids.is_empty() {
+			self.update_tip_record(tip_id, |tip_record| {
+				tip_record.set_funding_round_ids(funding_round_ids)
+			});
+		}
+	}
+
+	/// Takes a cancelled tip back out of the rounds it counted towards, or puts it back when the
+	/// cancellation failed. A tip can't be cancelled once one of its rounds started scoring.
+	pub(crate) fn apply_round_cancellation(&mut self, tip_record: &TipRecord, cancelled: bool) {
+		let key = tip_record.get_key();
+		let sender = tip_record.get_sender();
+		let amount = tip_record.get_amount();
+
+		for id in tip_record.get_funding_round_ids() {
+			let collecting = match self.funding_rounds.get(id) {
+				Some(funding_round) => funding_round.get_phase() == FundingRoundPhase::Collecting,
+				None => false,
+			};
+
+			if !collecting {
+				assert!(!cancelled, "TipCountedInFundingRound");
+				continue
+			}
+
+			self.update_round_contribution(*id, key, sender, amount, !cancelled);
+		}
+	}
+
+	/// Adds an amount to (or removes it from) the contribution of a sender to a reference of a
+	/// round, and updates the sum of the square roots of the reference with it.
+	fn update_round_contribution(
+		&mut self,
+		id: FundingRoundId,
+		key: &TipsBalanceKey,
+		sender: &AccountId,
+		amount: Balance,
+		added: bool,
+	) {
+		let contribution_key = (id, key.clone(), sender.clone());
+		let contribution = self.round_contributions.get(&contribution_key).unwrap_or_default();
+		let round_reference = self.round_reference_of(id, key);
+		let (updated, total) = if added {
+			(contribution + amount, round_reference.total.0 + amount)
+		} else {
+			(
+				contribution.checked_sub(amount).expect("InsufficientBalance"),
+				round_reference.total.0 - amount,
+			)
+		};
+		let roots = round_reference.sum_of_roots.0 - self.integer_sqrt(contribution) +
+			self.integer_sqrt(updated);
+		let contributors =
+			round_reference.contributors + contribution.is_zero() as u64 - updated.is_zero() as u64;
+		let round_reference =
+			RoundReference { contributors, total: U128(total), sum_of_roots: U128(roots) };
+
+		if updated.is_zero() {
+			self.round_contributions.remove(&contribution_key);
+		} else {
+			self.round_contributions.insert(&contribution_key, &updated);
+		}
+
+		self.round_references.insert(&(id, key.clone()), &round_reference);
+	}
+
+	/// Share of the pool a reference receives, given the total score of the round.
+	pub(crate) fn round_match(
+		&self,
+		funding_round: &FundingRound,
+		round_reference: &RoundReference,
+		total_score: U256,
+	) -> Balance {
+		if total_score.is_zero() {
+			return Zero::zero()
+		}
+
+		let pool = U256::from(funding_round.get_pool());
+
+		(pool * round_reference.score() / total_score).as_u128()
+	}
+
+	/// Processes up to `limit` references of a round that ended. The scores of all references are
+	/// summed first, then each reference receives its match as a tip from the creator. What is left
+	/// after rounding goes back to the deposit of the creator.
+	pub(crate) fn process_funding_round(
+		&mut self,
+		funding_round: FundingRound,
+		limit: u64,
+	) -> FundingRound {
+		let mut funding_round = match funding_round.get_phase() {
+			FundingRoundPhase::Collecting => funding_round.set_phase(FundingRoundPhase::Scoring),
+			_ => funding_round,
+		};
+		let id = funding_round.get_id();
+		let total_references = funding_round.get_references().len() as u64;
+		let mut processed = 0;
+
+		loop {
+			let phase = funding_round.get_phase();
+
+			if phase == FundingRoundPhase::Finalized {
+				break
+			}
+
+			if funding_round.get_cursor() >= total_references {
+				funding_round = match phase {
+					FundingRoundPhase::Scoring =>
+						funding_round.set_phase(FundingRoundPhase::Distributing),
+					_ => self.close_funding_round(funding_round),
+				};
+				continue
+			}
+
+			if processed == limit {
+				break
+			}
+
+			let index = funding_round.get_cursor() as usize;
+			let tips_balance_info = funding_round.get_references()[index].clone();
+			let key = tips_balance_info.key();
+			let round_reference = self.round_reference_of(id, &key);
+
+			funding_round = match phase {
+				FundingRoundPhase::Scoring => {
+					self.remove_funding_round_id(&key, id);
+
+					funding_round.add_score(round_reference.score())
+				},
+				_ => {
+					let total_score = funding_round.get_total_score();
+					let matched = self.round_match(&funding_round, &round_reference, total_score);
+
+					if !matched.is_zero() {
+						let creator = funding_round.get_creator().clone();
+
+						self.create_tips_balance(
+							&creator,
+							&tips_balance_info,
+							&matched,
+							None,
+							TipKind::Credit,
+						);
+					}
+
+					funding_round.add_distributed(matched)
+				},
+			}
+			.next_cursor();
+			processed += 1;
+		}
+
+		funding_round
+	}
+
+	fn close_funding_round(&mut self, funding_round: FundingRound) -> FundingRound {
+		let creator = funding_round.get_creator().clone();
+		let ft_identifier = funding_round.get_ft_identifier().to_string();
+		let remainder = funding_round.get_pool() - funding_round.get_distributed();
+
+		if remainder > 0 {
+			self.add_deposit(&creator, &ft_identifier, remainder);
+		}
+
+		log!("Funding round {} finalized", funding_round.get_id());
+
+		funding_round.set_phase(FundingRoundPhase::Finalized)
+	}
+}
@@ -0,0 +1,363 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod funding_rounds_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn integer_sqrt_works() {
+		// Initialize contract
+		let context = get_context(accounts(1));
+		testing_env!(context.build());
+		let contract = Tipping::new(None);
+
+		// Test
+		assert_eq!(contract.integer_sqrt(0), 0);
+		assert_eq!(contract.integer_sqrt(1), 1);
+		assert_eq!(contract.integer_sqrt(8), 2);
+		assert_eq!(contract.integer_sqrt(9), 3);
+		assert_eq!(contract.integer_sqrt(10u128.pow(24)), 10u128.pow(12));
+		assert_eq!(contract.integer_sqrt(u128::MAX), u64::MAX as u128);
+	}
+
+	#[test]
+	fn tips_are_tracked_during_round() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(4) funds a round of 82 over post_1 and post_2 until timestamp 1000
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(82).build());
+		let id = contract.create_funding_round(
+			String::from("native"),
+			U128(82),
+			vec![post_1.clone(), post_2.clone()],
+			None,
+			1000,
+		);
+
+		// accounts(2) and accounts(3) tip post_1, accounts(2) tips post_2
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(4).build());
+		contract.send_tip(post_1.clone(), None);
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(9).build());
+		contract.send_tip(post_1.clone(), None);
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(16).build());
+		contract.send_tip(post_2.clone(), None);
+
+		// Test
+		let matches = contract.get_funding_round_matches(id);
+
+		assert_eq!(contract.get_round_contribution(id, post_1, accounts(3)).0, 9);
+		assert_eq!(matches[0].contributors, 2);
+		assert_eq!(matches[0].total.0, 13);
+		assert_eq!(matches[0].matched.0, 50);
+		assert_eq!(matches[1].contributors, 1);
+		assert_eq!(matches[1].matched.0, 32);
+	}
+
+	#[test]
+	fn finalize_funding_round_in_chunks() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(4) funds a round of 82 over post_1 and post_2 until timestamp 1000
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(82).build());
+		let id = contract.create_funding_round(
+			String::from("native"),
+			U128(82),
+			vec![post_1.clone(), post_2.clone()],
+			None,
+			1000,
+		);
+
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(4).build());
+		contract.send_tip(post_1.clone(), None);
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(9).build());
+		contract.send_tip(post_1.clone(), None);
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(16).build());
+		contract.send_tip(post_2.clone(), None);
+
+		// Finalize one reference at a time after the round ended
+		testing_env!(context.block_timestamp(1000).attached_deposit(0).build());
+		let phases: Vec<FundingRoundPhase> =
+			(0..4).map(|_| contract.finalize_funding_round(id, 1)).collect();
+
+		// Test
+		let post_1_balance = contract.get_tips_balance(post_1.clone()).unwrap();
+		let post_2_balance = contract.get_tips_balance(post_2).unwrap();
+		let funding_round = contract.get_funding_round(id).unwrap();
+
+		assert_eq!(
+			phases,
+			vec![
+				FundingRoundPhase::Scoring,
+				FundingRoundPhase::Distributing,
+				FundingRoundPhase::Distributing,
+				FundingRoundPhase::Finalized,
+			]
+		);
+		assert_eq!(post_1_balance.tips_balance.get_amount(), 63);
+		assert_eq!(post_2_balance.tips_balance.get_amount(), 48);
+		assert_eq!(funding_round.get_distributed(), 82);
+		assert!(contract.get_funding_rounds_by_reference(post_1).is_empty());
+	}
+
+	#[test]
+	fn tips_outside_window_are_not_tracked() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(4) funds a round of 82 over post_1 and post_2 until timestamp 1000
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(82).build());
+		let id = contract.create_funding_round(
+			String::from("native"),
+			U128(82),
+			vec![post_1.clone(), post_2.clone()],
+			None,
+			1000,
+		);
+
+		// Tip after the round ended
+		testing_env!(context
+			.predecessor_account_id(accounts(2))
+			.block_timestamp(1000)
+			.attached_deposit(4)
+			.build());
+		contract.send_tip(post_1.clone(), None);
+
+		// Test
+		assert_eq!(contract.get_round_contribution(id, post_1, accounts(2)).0, 0);
+	}
+
+	#[test]
+	fn unmatched_pool_returns_to_creator() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(4) funds a round of 82 over post_1 and post_2 until timestamp 1000
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(82).build());
+		let id = contract.create_funding_round(
+			String::from("native"),
+			U128(82),
+			vec![post_1.clone(), post_2.clone()],
+			None,
+			1000,
+		);
+
+		// Finalize a round nobody tipped in
+		testing_env!(context.block_timestamp(1000).build());
+		let phase = contract.finalize_funding_round(id, 10);
+
+		// Test
+		assert_eq!(phase, FundingRoundPhase::Finalized);
+		assert_eq!(contract.get_deposit(accounts(4), String::from("native")).0, 82);
+	}
+
+	#[test]
+	fn cancel_tip_reverses_round_contribution() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(4) funds a round of 82 over post_1 and post_2 until timestamp 1000
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(82).build());
+		let id = contract.create_funding_round(
+			String::from("native"),
+			U128(82),
+			vec![post_1.clone(), post_2.clone()],
+			None,
+			1000,
+		);
+
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(4).build());
+		contract.send_tip(post_1.clone(), None);
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(9).build());
+		contract.send_tip(post_1.clone(), None);
+
+		// Cancel the tip of accounts(3)
+		let tip_record = &contract.get_tip_records_by_sender(accounts(3), None, None).data[0];
+		let tip_id = tip_record.tip_record.get_id();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+
+		// Test
+		let matches = contract.get_funding_round_matches(id);
+
+		assert_eq!(contract.get_round_contribution(id, post_1, accounts(3)).0, 0);
+		assert_eq!(matches[0].contributors, 1);
+		assert_eq!(matches[0].total.0, 4);
+		assert_eq!(matches[0].matched.0, 82);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipCountedInFundingRound")]
+	fn cant_cancel_tip_counted_in_finalized_round() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(4) funds a round of 82 over post_1 and post_2 until timestamp 1000
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(82).build());
+		let id = contract.create_funding_round(
+			String::from("native"),
+			U128(82),
+			vec![post_1.clone(), post_2.clone()],
+			None,
+			1000,
+		);
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(9).build());
+		contract.send_tip(post_1.clone(), None);
+
+		// Finalize the round
+		testing_env!(context.block_timestamp(1000).attached_deposit(0).build());
+		contract.finalize_funding_round(id, 10);
+
+		// Test
+		let tip_record = &contract.get_tip_records_by_sender(accounts(3), None, None).data[0];
+		let tip_id = tip_record.tip_record.get_id();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipNotCancellable")]
+	fn cant_cancel_round_match() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(4) funds a round of 82 over post_1 and post_2 until timestamp 1000
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(82).build());
+		let id = contract.create_funding_round(
+			String::from("native"),
+			U128(82),
+			vec![post_1.clone(), post_2.clone()],
+			None,
+			1000,
+		);
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(9).build());
+		contract.send_tip(post_1.clone(), None);
+
+		// Finalize the round
+		testing_env!(context.block_timestamp(1000).attached_deposit(0).build());
+		contract.finalize_funding_round(id, 10);
+
+		// Test
+		let tip_record = &contract.get_tip_records_by_sender(accounts(4), None, None).data[0];
+		let tip_id = tip_record.tip_record.get_id();
+
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+	}
+
+	#[test]
+	#[should_panic(expected = "Unauthorized")]
+	fn cant_create_funding_round_for_other_server() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Test
+		testing_env!(context.attached_deposit(10).build());
+		contract.create_funding_round(String::from("native"), U128(10), vec![post_1], None, 1000);
+	}
+
+	#[test]
+	#[should_panic(expected = "FundingRoundNotEnded")]
+	fn cant_finalize_funding_round_before_end() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(4) funds a round of 82 over post_1 and post_2 until timestamp 1000
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(82).build());
+		let id = contract.create_funding_round(
+			String::from("native"),
+			U128(82),
+			vec![post_1.clone(), post_2.clone()],
+			None,
+			1000,
+		);
+
+		// Test
+		testing_env!(context.attached_deposit(0).build());
+		contract.finalize_funding_round(id, 10);
+	}
+
+	#[test]
+	#[should_panic(expected = "DuplicateReference")]
+	fn cant_create_funding_round_with_duplicate_references() {
+		// Initialize contract
+		let mut context = get_context(accounts(4));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Test
+		testing_env!(context.attached_deposit(10).build());
+		contract.create_funding_round(
+			String::from("native"),
+			U128(10),
+			vec![post_1.clone(), post_1],
+			None,
+			1000,
+		);
+	}
+}
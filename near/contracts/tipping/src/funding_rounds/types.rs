@@ -0,0 +1,159 @@
+use crate::*;
+
+pub type FundingRoundId = u64;
+pub type RoundReferenceKey = (FundingRoundId, TipsBalanceKey);
+pub type RoundContributionKey = (FundingRoundId, TipsBalanceKey, AccountId);
+
+pub const MAX_ROUND_REFERENCES: usize = 50;
+pub const MAX_ROUNDS_PER_REFERENCE: usize = 5;
+
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum FundingRoundPhase {
+	Collecting,
+	Scoring,
+	Distributing,
+	Finalized,
+}
+
+/// Grants round that distributes a pool over its references quadratically. Every reference scores
+/// the square of the sum of the square roots of what each sender tipped it during the window, and
+/// receives the pool in proportion to its score once the round is finalized.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FundingRound {
+	id: FundingRoundId,
+	creator: AccountId,
+	ft_identifier: FtIdentifier,
+	pool: U128,
+	references: Vec<TipsBalanceInfo>,
+	starts_at: Timestamp,
+	ends_at: Timestamp,
+	phase: FundingRoundPhase,
+	cursor: u64,
+	distributed: U128,
+	#[serde(skip)]
+	total_score: [u64; 4],
+}
+impl FundingRound {
+	pub fn new(
+		id: FundingRoundId,
+		creator: &AccountId,
+		ft_identifier: &str,
+		pool: Balance,
+		references: Vec<TipsBalanceInfo>,
+		starts_at: Timestamp,
+		ends_at: Timestamp,
+	) -> Self {
+		Self {
+			id,
+			creator: creator.clone(),
+			ft_identifier: ft_identifier.to_string(),
+			pool: U128(pool),
+			references,
+			starts_at,
+			ends_at,
+			phase: FundingRoundPhase::Collecting,
+			cursor: 0,
+			distributed: U128(Zero::zero()),
+			total_score: [0; 4],
+		}
+	}
+
+	pub fn get_id(&self) -> FundingRoundId {
+		self.id
+	}
+
+	pub fn get_creator(&self) -> &AccountId {
+		&self.creator
+	}
+
+	pub fn get_ft_identifier(&self) -> &str {
+		&self.ft_identifier
+	}
+
+	pub fn get_pool(&self) -> Balance {
+		self.pool.0
+	}
+
+	pub fn get_references(&self) -> &Vec<TipsBalanceInfo> {
+		&self.references
+	}
+
+	pub fn get_ends_at(&self) -> Timestamp {
+		self.ends_at
+	}
+
+	pub fn get_phase(&self) -> FundingRoundPhase {
+		self.phase
+	}
+
+	pub fn get_cursor(&self) -> u64 {
+		self.cursor
+	}
+
+	pub fn get_distributed(&self) -> Balance {
+		self.distributed.0
+	}
+
+	pub fn get_total_score(&self) -> U256 {
+		U256(self.total_score)
+	}
+
+	pub fn is_collecting(&self, now: Timestamp) -> bool {
+		self.phase == FundingRoundPhase::Collecting && self.starts_at <= now && now < self.ends_at
+	}
+
+	pub fn add_score(mut self, score: U256) -> Self {
+		self.total_score = (self.get_total_score() + score).0;
+		self
+	}
+
+	pub fn add_distributed(mut self, amount: Balance) -> Self {
+		self.distributed = U128(self.get_distributed() + amount);
+		self
+	}
+
+	pub fn next_cursor(mut self) -> Self {
+		self.cursor += 1;
+		self
+	}
+
+	pub fn set_phase(mut self, phase: FundingRoundPhase) -> Self {
+		self.phase = phase;
+		self.cursor = 0;
+		self
+	}
+}
+
+/// Tips a reference received during a round.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoundReference {
+	pub contributors: u64,
+	pub total: U128,
+	pub sum_of_roots: U128,
+}
+impl Default for RoundReference {
+	fn default() -> Self {
+		Self { contributors: 0, total: U128(Zero::zero()), sum_of_roots: U128(Zero::zero()) }
+	}
+}
+impl RoundReference {
+	pub fn score(&self) -> U256 {
+		let sum_of_roots = U256::from(self.sum_of_roots.0);
+
+		sum_of_roots * sum_of_roots
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoundMatch {
+	pub tips_balance_info: TipsBalanceInfo,
+	pub contributors: u64,
+	pub total: U128,
+	pub matched: U128,
+}
@@ -0,0 +1,58 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_funding_round(&self, funding_round_id: FundingRoundId) -> Option<FundingRound> {
+		self.funding_rounds.get(&funding_round_id)
+	}
+
+	pub fn get_funding_rounds_by_reference(
+		&self,
+		tips_balance_info: TipsBalanceInfo,
+	) -> Vec<FundingRound> {
+		self.funding_round_ids_of(&tips_balance_info.key())
+			.iter()
+			.filter_map(|id| self.funding_rounds.get(id))
+			.collect()
+	}
+
+	/// Returns what every reference of a round received so far and the match it would get if the
+	/// round ended now.
+	pub fn get_funding_round_matches(&self, funding_round_id: FundingRoundId) -> Vec<RoundMatch> {
+		let funding_round = match self.funding_rounds.get(&funding_round_id) {
+			Some(funding_round) => funding_round,
+			None => return Vec::new(),
+		};
+		let round_references: Vec<RoundReference> = funding_round
+			.get_references()
+			.iter()
+			.map(|info| self.round_reference_of(funding_round_id, &info.key()))
+			.collect();
+		let total_score = round_references
+			.iter()
+			.fold(U256::zero(), |total, reference| total + reference.score());
+
+		funding_round
+			.get_references()
+			.iter()
+			.zip(round_references)
+			.map(|(tips_balance_info, round_reference)| RoundMatch {
+				tips_balance_info: tips_balance_info.clone(),
+				contributors: round_reference.contributors,
+				total: round_reference.total,
+				matched: U128(self.round_match(&funding_round, &round_reference, total_score)),
+			})
+			.collect()
+	}
+
+	pub fn get_round_contribution(
+		&self,
+		funding_round_id: FundingRoundId,
+		tips_balance_info: TipsBalanceInfo,
+		sender: AccountId,
+	) -> U128 {
+		let key = (funding_round_id, tips_balance_info.key(), sender);
+
+		U128(self.round_contributions.get(&key).unwrap_or_default())
+	}
+}
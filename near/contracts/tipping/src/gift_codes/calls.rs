@@ -0,0 +1,110 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Locks an amount of the deposit of the caller under the sha256 hash of a secret.
+	#[payable]
+	pub fn create_gift_code(
+		&mut self,
+		code_hash: Base58CryptoHash,
+		ft_identifier: FtIdentifier,
+		amount: U128,
+		expires_at: Timestamp,
+	) -> Base58CryptoHash {
+		let purchaser = env::predecessor_account_id();
+		let mut code_hashes = self.gift_code_hashes_of(&purchaser);
+
+		assert!(amount.0 > 0, "NothingToDeposit");
+		assert!(expires_at > env::block_timestamp(), "InvalidExpiry");
+		assert!(
+			self.gift_codes.get(&code_hash).is_none() &&
+				!self.revealed_gift_codes.contains(&code_hash),
+			"GiftCodeAlreadyExists"
+		);
+		assert!(code_hashes.len() < MAX_GIFT_CODES_PER_PURCHASER, "TooManyGiftCodes");
+		assert!(self.ft_identifiers.get(&ft_identifier).is_some(), "TokenNotFound");
+		self.assert_not_paused(PauseFeature::Tipping, Some(&ft_identifier));
+
+		self.add_attached_deposit(&purchaser);
+
+		self.remove_deposit(&purchaser, &ft_identifier, amount.0);
+
+		let gift_code = GiftCode::new(code_hash, &purchaser, &ft_identifier, amount.0, expires_at);
+
+		code_hashes.push(code_hash);
+
+		self.gift_codes.insert(&code_hash, &gift_code);
+		self.gift_code_hashes.insert(&purchaser, &code_hashes);
+
+		log!("{} created a gift code", purchaser);
+
+		code_hash
+	}
+
+	/// Commits the caller to a secret before revealing it, with `gift_code_commitment` of the
+	/// account id of the caller and the secret. Committing again replaces the commitment.
+	pub fn commit_gift_code(&mut self, commitment: Base58CryptoHash) {
+		let account_id = env::predecessor_account_id();
+
+		self.gift_code_commitments
+			.insert(&account_id, &GiftCodeCommitment::new(commitment));
+	}
+
+	/// Reveals the secret of a gift code and tips its whole amount to a reference on behalf of the
+	/// caller.
+	pub fn redeem_gift_code(
+		&mut self,
+		secret: String,
+		tips_balance_info: TipsBalanceInfo,
+		memo: Option<String>,
+	) -> TipId {
+		let sender = env::predecessor_account_id();
+		let gift_code = self.take_gift_code(&secret, &sender);
+		let ft_identifier = gift_code.get_ft_identifier();
+		let amount = gift_code.get_amount();
+
+		assert!(tips_balance_info.get_ft_identifier() == ft_identifier, "InvalidFtIdentifier");
+
+		self.add_deposit(&sender, ft_identifier, amount);
+		self.internal_tip_from_balance(&sender, &tips_balance_info, amount, memo, TipKind::Direct)
+	}
+
+	/// Reveals the secret of a gift code and transfers its amount to the caller. A failed transfer
+	/// is added to the deposit of the caller.
+	#[payable]
+	pub fn withdraw_gift_code(&mut self, secret: String) {
+		assert_one_yocto();
+
+		let account_id = env::predecessor_account_id();
+		let gift_code = self.take_gift_code(&secret, &account_id);
+		let ft_identifier = gift_code.get_ft_identifier().to_string();
+		let amount = gift_code.get_amount();
+
+		self.assert_not_paused(PauseFeature::Claiming, Some(&ft_identifier));
+
+		self.transfer(&account_id, &ft_identifier, amount).then(
+			Self::ext(env::current_account_id()).resolve_withdraw_gift_code(
+				account_id,
+				ft_identifier,
+				amount,
+			),
+		);
+	}
+
+	/// Puts an expired gift code back in the deposit of the purchaser, callable by anyone.
+	pub fn refund_gift_code(&mut self, code_hash: Base58CryptoHash) -> U128 {
+		let gift_code = self.gift_codes.get(&code_hash).expect("GiftCodeNotExists");
+		let purchaser = gift_code.get_purchaser().clone();
+		let amount = gift_code.get_amount();
+
+		assert!(gift_code.is_expired(env::block_timestamp()), "GiftCodeNotExpired");
+		self.assert_not_paused(PauseFeature::Claiming, Some(gift_code.get_ft_identifier()));
+
+		self.remove_gift_code(&gift_code);
+		self.add_deposit(&purchaser, gift_code.get_ft_identifier(), amount);
+
+		log!("Gift code refunded to {}", purchaser);
+
+		U128(amount)
+	}
+}
@@ -0,0 +1,64 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn gift_code_hashes_of(&self, purchaser: &AccountId) -> Vec<Base58CryptoHash> {
+		self.gift_code_hashes.get(purchaser).unwrap_or_default()
+	}
+
+	pub(crate) fn remove_gift_code(&mut self, gift_code: &GiftCode) {
+		let code_hash = gift_code.get_code_hash();
+		let purchaser = gift_code.get_purchaser();
+		let mut code_hashes = self.gift_code_hashes_of(purchaser);
+
+		code_hashes.retain(|hash| *hash != code_hash);
+
+		if code_hashes.is_empty() {
+			self.gift_code_hashes.remove(purchaser);
+		} else {
+			self.gift_code_hashes.insert(purchaser, &code_hashes);
+		}
+
+		self.gift_codes.remove(&code_hash);
+	}
+
+	/// Removes the gift code matching the secret and returns it, the secret is public from here on.
+	/// The account must have committed to the secret in an earlier block.
+	pub(crate) fn take_gift_code(&mut self, secret: &str, account_id: &AccountId) -> GiftCode {
+		let code_hash: CryptoHash = env::sha256(secret.as_bytes()).try_into().unwrap();
+		let code_hash: Base58CryptoHash = code_hash.into();
+		let gift_code = self.gift_codes.get(&code_hash).expect("GiftCodeNotExists");
+		let commitment =
+			self.gift_code_commitments.remove(account_id).expect("GiftCodeNotCommitted");
+		let now = env::block_timestamp();
+
+		assert!(!gift_code.is_expired(now), "GiftCodeExpired");
+		assert!(
+			commitment.get_commitment() == gift_code_commitment(account_id, secret),
+			"GiftCodeNotCommitted"
+		);
+		assert!(commitment.get_committed_at() < now, "CommitmentTooRecent");
+
+		self.remove_gift_code(&gift_code);
+		self.revealed_gift_codes.insert(&code_hash);
+
+		gift_code
+	}
+
+	pub(crate) fn internal_resolve_withdraw_gift_code(
+		&mut self,
+		account_id: AccountId,
+		ft_identifier: FtIdentifier,
+		amount: Balance,
+	) {
+		require!(env::promise_results_count() == 1);
+
+		let (symbol, formatted) = self.formatted_amount_of(&ft_identifier, amount);
+
+		match env::promise_result(0) {
+			PromiseResult::Successful(_) => {
+				log!("{} withdrew {} {} from a gift code", account_id, formatted, symbol);
+			},
+			_ => self.add_deposit(&account_id, &ft_identifier, amount),
+		}
+	}
+}
@@ -0,0 +1,14 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	#[private]
+	pub fn resolve_withdraw_gift_code(
+		&mut self,
+		account_id: AccountId,
+		ft_identifier: FtIdentifier,
+		amount: Balance,
+	) {
+		self.internal_resolve_withdraw_gift_code(account_id, ft_identifier, amount);
+	}
+}
@@ -0,0 +1,313 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod gift_codes_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env, RuntimeFeesConfig, VMConfig,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	fn code_hash(secret: &str) -> Base58CryptoHash {
+		let code_hash: CryptoHash = env::sha256(secret.as_bytes()).try_into().unwrap();
+
+		code_hash.into()
+	}
+
+	#[test]
+	fn redeem_gift_code_tips_reference() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let commitment = gift_code_commitment(&accounts(2), "secret");
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// accounts(2) commits to the secret, then reveals it in a later block
+		testing_env!(context
+			.predecessor_account_id(accounts(2))
+			.block_timestamp(100)
+			.attached_deposit(0)
+			.build());
+		contract.commit_gift_code(commitment);
+
+		testing_env!(context.block_timestamp(200).build());
+		contract.redeem_gift_code(String::from("secret"), post_1.clone(), None);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(post_1).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 100);
+		assert_eq!(contract.get_deposit(accounts(2), String::from("native")).0, 0);
+		assert!(contract.get_gift_code(code_hash("secret")).is_none());
+		assert!(contract.get_gift_code_commitment(accounts(2)).is_none());
+		assert!(contract.get_gift_codes_by_purchaser(accounts(1)).is_empty());
+	}
+
+	#[test]
+	fn cancel_redeemed_gift_code_tip_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let commitment = gift_code_commitment(&accounts(2), "secret");
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// accounts(2) redeems the gift code and cancels the tip
+		testing_env!(context
+			.predecessor_account_id(accounts(2))
+			.block_timestamp(100)
+			.attached_deposit(0)
+			.build());
+		contract.commit_gift_code(commitment);
+
+		testing_env!(context.block_timestamp(200).build());
+		let tip_id = contract.redeem_gift_code(String::from("secret"), post_1.clone(), None);
+
+		testing_env!(context.attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(post_1).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 0);
+		assert!(contract.get_tip_record(tip_id).unwrap().tip_record.is_cancelled());
+	}
+
+	#[test]
+	fn failed_gift_code_withdrawal_goes_to_deposit() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let commitment = gift_code_commitment(&accounts(2), "secret");
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// accounts(2) commits to the secret, then reveals it in a later block
+		testing_env!(context
+			.predecessor_account_id(accounts(2))
+			.block_timestamp(100)
+			.attached_deposit(0)
+			.build());
+		contract.commit_gift_code(commitment);
+
+		testing_env!(context.block_timestamp(200).attached_deposit(1).build());
+		contract.withdraw_gift_code(String::from("secret"));
+
+		// Transfer fails
+		testing_env!(
+			context.predecessor_account_id(accounts(0)).build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![PromiseResult::Failed],
+		);
+		contract.resolve_withdraw_gift_code(accounts(2), String::from("native"), 100);
+
+		// Test
+		assert_eq!(contract.get_deposit(accounts(2), String::from("native")).0, 100);
+		assert!(contract.get_gift_code(code_hash("secret")).is_none());
+	}
+
+	#[test]
+	fn refund_gift_code_after_expiry() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// Test
+		testing_env!(context.block_timestamp(500).attached_deposit(0).build());
+		assert!(contract.get_gift_code(code_hash("secret")).unwrap().is_redeemable);
+
+		testing_env!(context.predecessor_account_id(accounts(3)).block_timestamp(1000).build());
+		assert!(!contract.get_gift_code(code_hash("secret")).unwrap().is_redeemable);
+
+		let amount = contract.refund_gift_code(code_hash("secret"));
+
+		assert_eq!(amount.0, 100);
+		assert_eq!(contract.get_deposit(accounts(1), String::from("native")).0, 100);
+	}
+
+	#[test]
+	#[should_panic(expected = "GiftCodeNotExists")]
+	fn cant_redeem_with_wrong_secret() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// Test
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(0).build());
+		contract.redeem_gift_code(String::from("guess"), post_1, None);
+	}
+
+	#[test]
+	#[should_panic(expected = "GiftCodeNotCommitted")]
+	fn cant_redeem_secret_committed_by_other_account() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let commitment = gift_code_commitment(&accounts(2), "secret");
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// accounts(3) copies the commitment of accounts(2)
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.block_timestamp(100)
+			.attached_deposit(0)
+			.build());
+		contract.commit_gift_code(commitment);
+
+		// Test
+		testing_env!(context.block_timestamp(200).build());
+		contract.redeem_gift_code(String::from("secret"), post_1, None);
+	}
+
+	#[test]
+	#[should_panic(expected = "CommitmentTooRecent")]
+	fn cant_redeem_in_block_of_commitment() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let commitment = gift_code_commitment(&accounts(2), "secret");
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// Test
+		testing_env!(context
+			.predecessor_account_id(accounts(2))
+			.block_timestamp(100)
+			.attached_deposit(0)
+			.build());
+		contract.commit_gift_code(commitment);
+		contract.redeem_gift_code(String::from("secret"), post_1, None);
+	}
+
+	#[test]
+	#[should_panic(expected = "GiftCodeExpired")]
+	fn cant_redeem_expired_gift_code() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let commitment = gift_code_commitment(&accounts(2), "secret");
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// accounts(2) commits to the secret
+		testing_env!(context
+			.predecessor_account_id(accounts(2))
+			.block_timestamp(100)
+			.attached_deposit(0)
+			.build());
+		contract.commit_gift_code(commitment);
+
+		// Test
+		testing_env!(context.block_timestamp(1000).build());
+		contract.redeem_gift_code(String::from("secret"), post_1, None);
+	}
+
+	#[test]
+	#[should_panic(expected = "GiftCodeAlreadyExists")]
+	fn cant_reuse_code_hash() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// Test
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(10).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(10), 1000);
+	}
+
+	#[test]
+	#[should_panic(expected = "GiftCodeAlreadyExists")]
+	fn cant_reuse_revealed_code_hash() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let commitment = gift_code_commitment(&accounts(2), "secret");
+
+		// accounts(1) buys a gift code of 100 that expires at timestamp 1000
+		testing_env!(context.attached_deposit(100).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(100), 1000);
+
+		// accounts(2) redeems the gift code
+		testing_env!(context
+			.predecessor_account_id(accounts(2))
+			.block_timestamp(100)
+			.attached_deposit(0)
+			.build());
+		contract.commit_gift_code(commitment);
+
+		testing_env!(context.block_timestamp(200).build());
+		contract.redeem_gift_code(String::from("secret"), post_1, None);
+
+		// Test
+		testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(10).build());
+		contract.create_gift_code(code_hash("secret"), String::from("native"), U128(10), 1000);
+	}
+}
@@ -0,0 +1,92 @@
+use crate::*;
+
+pub const MAX_GIFT_CODES_PER_PURCHASER: usize = 20;
+
+/// Amount locked under the sha256 hash of a secret. Whoever reveals the secret before the expiry
+/// can tip with it or withdraw it, after that it goes back to the purchaser. A code hash can only
+/// be revealed once.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GiftCode {
+	code_hash: Base58CryptoHash,
+	purchaser: AccountId,
+	ft_identifier: FtIdentifier,
+	amount: U128,
+	expires_at: Timestamp,
+	created_at: Timestamp,
+}
+impl GiftCode {
+	pub fn new(
+		code_hash: Base58CryptoHash,
+		purchaser: &AccountId,
+		ft_identifier: &str,
+		amount: Balance,
+		expires_at: Timestamp,
+	) -> Self {
+		Self {
+			code_hash,
+			purchaser: purchaser.clone(),
+			ft_identifier: ft_identifier.to_string(),
+			amount: U128(amount),
+			expires_at,
+			created_at: env::block_timestamp(),
+		}
+	}
+
+	pub fn get_code_hash(&self) -> Base58CryptoHash {
+		self.code_hash
+	}
+
+	pub fn get_purchaser(&self) -> &AccountId {
+		&self.purchaser
+	}
+
+	pub fn get_ft_identifier(&self) -> &str {
+		&self.ft_identifier
+	}
+
+	pub fn get_amount(&self) -> Balance {
+		self.amount.0
+	}
+
+	pub fn is_expired(&self, now: Timestamp) -> bool {
+		now >= self.expires_at
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GiftCodeWithStatus {
+	pub gift_code: GiftCode,
+	pub is_redeemable: bool,
+}
+
+/// Commitment to the secret of a gift code, made in a block before the secret is revealed so that
+/// the revealing transaction can't be front-run.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GiftCodeCommitment {
+	commitment: Base58CryptoHash,
+	committed_at: Timestamp,
+}
+impl GiftCodeCommitment {
+	pub fn new(commitment: Base58CryptoHash) -> Self {
+		Self { commitment, committed_at: env::block_timestamp() }
+	}
+
+	pub fn get_commitment(&self) -> Base58CryptoHash {
+		self.commitment
+	}
+
+	pub fn get_committed_at(&self) -> Timestamp {
+		self.committed_at
+	}
+}
+
+/// The sha256 hash of the borsh serialized account id and secret.
+pub fn gift_code_commitment(account_id: &AccountId, secret: &str) -> Base58CryptoHash {
+	let message = (account_id, secret).try_to_vec().unwrap();
+	let commitment: CryptoHash = env::sha256(&message).try_into().unwrap();
+
+	commitment.into()
+}
@@ -0,0 +1,22 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_gift_code(&self, code_hash: Base58CryptoHash) -> Option<GiftCodeWithStatus> {
+		self.gift_codes.get(&code_hash).map(|gift_code| GiftCodeWithStatus {
+			is_redeemable: !gift_code.is_expired(env::block_timestamp()),
+			gift_code,
+		})
+	}
+
+	pub fn get_gift_code_commitment(&self, account_id: AccountId) -> Option<GiftCodeCommitment> {
+		self.gift_code_commitments.get(&account_id)
+	}
+
+	pub fn get_gift_codes_by_purchaser(&self, purchaser: AccountId) -> Vec<GiftCode> {
+		self.gift_code_hashes_of(&purchaser)
+			.iter()
+			.filter_map(|code_hash| self.gift_codes.get(code_hash))
+			.collect()
+	}
+}
@@ -1,6 +1,10 @@
 use crate::*;
 
 impl Tipping {
+	pub(crate) fn assert_owner(&self) {
+		assert!(env::predecessor_account_id() == self.owner, "UnauthorizedAdmin");
+	}
+
 	pub(crate) fn create_ft_identifier(
 		&mut self,
 		ft_identifier: &FtIdentifier,
@@ -126,6 +130,35 @@ impl Tipping {
 		formatted.trim_end_matches('0').trim_end_matches('.').to_string()
 	}
 
+	/// Computes `amount * part / total` rounded down, without overflowing on large balances.
+	pub(crate) fn proportion(&self, amount: Balance, part: Balance, total: Balance) -> Balance {
+		if total.is_zero() {
+			return Zero::zero()
+		}
+
+		let result = U256::from(amount) * U256::from(part) / U256::from(total);
+
+		result.as_u128()
+	}
+
+	/// Returns the symbol and formatted amount of a balance in a token.
+	pub(crate) fn formatted_amount_of(
+		&self,
+		ft_identifier: &str,
+		amount: Balance,
+	) -> (String, String) {
+		match self.ft_identifiers.get(&ft_identifier.to_string()) {
+			Some(ft_identifier_info) => {
+				let symbol = ft_identifier_info.get_symbol().to_string();
+				let decimals = ft_identifier_info.get_decimals().into();
+				let formatted = self.formatted_balance(&amount.to_string(), decimals);
+
+				(symbol, formatted)
+			},
+			None => (String::new(), amount.to_string()),
+		}
+	}
+
 	pub(crate) fn tip_log(
 		&self,
 		sender: &AccountId,
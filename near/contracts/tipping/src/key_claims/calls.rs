@@ -0,0 +1,93 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Binds an unclaimed reference of the calling server to a public key, which is added to this
+	/// contract as an access key that can only call `claim_by_key`. Binding again replaces the key,
+	/// the allowance of the replaced key and any deposit above the allowance go back to the server.
+	#[payable]
+	pub fn bind_reference_key(
+		&mut self,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+		public_key: PublicKey,
+	) {
+		let server_id = env::signer_account_id();
+		let tips_balance_info =
+			TipsBalanceInfo::new(&server_id, &reference_type, &reference_id, "native");
+		let key = tips_balance_info.key();
+		let deposit = env::attached_deposit();
+
+		assert!(deposit >= CLAIM_KEY_ALLOWANCE, "InsufficientAttachedDeposit");
+		assert!(public_key.curve_type() == CurveType::ED25519, "InvalidPublicKey");
+		assert!(self.key_claims.get(&public_key).is_none(), "PublicKeyAlreadyBound");
+		assert!(self.reference_account_id(&tips_balance_info).is_none(), "ReferenceAlreadyClaimed");
+		self.assert_not_paused(PauseFeature::Referencing, None);
+		self.assert_reference_not_blocked(&tips_balance_info);
+
+		if let Some(previous_key) = self.reference_keys.get(&key) {
+			self.remove_claim_key(&previous_key);
+		}
+
+		if deposit > CLAIM_KEY_ALLOWANCE {
+			Promise::new(server_id).transfer(deposit - CLAIM_KEY_ALLOWANCE);
+		}
+
+		self.reference_keys.insert(&key, &public_key);
+		self.key_claims.insert(&public_key, &tips_balance_info);
+
+		Promise::new(env::current_account_id()).add_access_key(
+			public_key,
+			CLAIM_KEY_ALLOWANCE,
+			env::current_account_id(),
+			CLAIM_BY_KEY_METHOD.to_string(),
+		);
+	}
+
+	/// Removes the key bound to a reference of the calling server and gives its allowance back.
+	pub fn unbind_reference_key(
+		&mut self,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+	) {
+		let server_id = env::signer_account_id();
+		let key = TipsBalanceKey::new(&server_id, &reference_type, &reference_id, "native");
+		let public_key = self.reference_keys.get(&key).expect("ReferenceKeyNotExists");
+
+		self.remove_claim_key(&public_key);
+	}
+
+	/// Called with the bound key on this contract. Creates the account of the recipient, funded
+	/// with the whole native balance of the reference, then claims the reference for it and pays
+	/// out its fungible token balances.
+	pub fn claim_by_key(&mut self, account_id: AccountId, public_key: Option<PublicKey>) {
+		assert!(env::predecessor_account_id() == env::current_account_id(), "Unauthorized");
+		self.assert_not_paused(PauseFeature::Referencing, None);
+		self.assert_not_paused(PauseFeature::Claiming, Some("native"));
+
+		let claim_key = env::signer_account_pk();
+		let tips_balance_info = self.key_claims.get(&claim_key).expect("ReferenceKeyNotExists");
+
+		self.assert_reference_not_blocked(&tips_balance_info);
+		assert!(self.reference_account_id(&tips_balance_info).is_none(), "ReferenceAlreadyClaimed");
+
+		let key = tips_balance_info.key();
+		let tips_balance = self.tips_balances.get(&key).expect("TipsBalanceNotExists");
+		let amount = tips_balance.get_amount();
+
+		assert!(amount >= MIN_ACCOUNT_FUNDING, "InsufficientTipsBalance");
+
+		self.key_claims.remove(&claim_key);
+		self.tips_balances
+			.insert(&key, &tips_balance.set_balance(Zero::zero()).set_claimed());
+
+		self.new_account_promise(&account_id, public_key, amount).then(
+			Self::ext(env::current_account_id()).resolve_claim_by_key(
+				tips_balance_info,
+				account_id,
+				claim_key,
+				amount,
+			),
+		);
+	}
+}
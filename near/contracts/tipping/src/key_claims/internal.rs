@@ -0,0 +1,142 @@
+use crate::*;
+
+impl Tipping {
+	/// A new account is either implicit, named after the hex of its ed25519 key, or a sub-account
+	/// of this contract that gets a full access key.
+	pub(crate) fn new_account_promise(
+		&self,
+		account_id: &AccountId,
+		public_key: Option<PublicKey>,
+		amount: Balance,
+	) -> Promise {
+		let is_implicit = account_id.as_str().len() == 64 &&
+			account_id
+				.as_str()
+				.chars()
+				.all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+
+		if is_implicit {
+			return Promise::new(account_id.clone()).transfer(amount)
+		}
+
+		let suffix = format!(".{}", env::current_account_id());
+
+		assert!(account_id.as_str().ends_with(&suffix), "InvalidAccountId");
+
+		let public_key = public_key.expect("PublicKeyRequired");
+
+		Promise::new(account_id.clone())
+			.create_account()
+			.add_full_access_key(public_key)
+			.transfer(amount)
+	}
+
+	/// Deletes the access key of a claim key. The allowance goes back to the server, unless a claim
+	/// with the key is in flight.
+	pub(crate) fn remove_claim_key(&mut self, public_key: &PublicKey) {
+		if let Some(tips_balance_info) = self.key_claims.remove(public_key) {
+			self.reference_keys.remove(&tips_balance_info.key());
+
+			Promise::new(tips_balance_info.get_server_id().clone()).transfer(CLAIM_KEY_ALLOWANCE);
+		}
+
+		Promise::new(env::current_account_id()).delete_key(public_key.clone());
+	}
+
+	/// Binds every `TipsBalance` of the reference to the new account and pays out the ones in
+	/// fungible tokens that are not paused. Transfers that fail go back into the balances, ready
+	/// for `claim_tip`.
+	pub(crate) fn claim_reference_by_key(
+		&mut self,
+		tips_balance_info: &TipsBalanceInfo,
+		account_id: &AccountId,
+	) {
+		let ft_identifiers: Vec<FtIdentifier> = self.ft_identifiers.keys().collect();
+		let mut tips_balances = Vec::new();
+
+		for ft_identifier in ft_identifiers {
+			let key = TipsBalanceKey::new(
+				tips_balance_info.get_server_id(),
+				tips_balance_info.get_reference_type(),
+				tips_balance_info.get_reference_id(),
+				&ft_identifier,
+			);
+			let tips_balance = match self.tips_balances.get(&key) {
+				Some(tips_balance) => tips_balance.set_account_id(account_id),
+				None => continue,
+			};
+
+			self.tips_balances.insert(&key, &tips_balance);
+			self.update_top_references(&tips_balance);
+			self.reset_contributions(&key);
+
+			let is_payable = ft_identifier != "native" &&
+				!tips_balance.get_amount().is_zero() &&
+				!self.is_paused(PauseFeature::Claiming, Some(&ft_identifier));
+
+			if is_payable {
+				self.debit_claimed_amount(tips_balance.clone(), tips_balance.get_amount());
+				tips_balances.push(tips_balance);
+			}
+		}
+
+		self.claim_reference_log(
+			tips_balance_info.get_reference_type(),
+			tips_balance_info.get_reference_id(),
+		);
+
+		if tips_balances.is_empty() {
+			return
+		}
+
+		let payouts: Vec<Vec<Payout>> = tips_balances
+			.iter()
+			.map(|tips_balance| {
+				self.revenue_payouts(tips_balance, account_id, tips_balance.get_amount())
+			})
+			.collect();
+		let mut tips_promise: Option<Promise> = None;
+
+		for (tips_balance, payouts) in tips_balances.iter().zip(payouts.iter()) {
+			let promise = self.transfer_payouts(tips_balance.get_ft_identifier(), payouts);
+
+			tips_promise = match tips_promise {
+				Some(tips_promise) => Some(tips_promise.and(promise)),
+				None => Some(promise),
+			};
+		}
+
+		tips_promise.unwrap().then(
+			Self::ext(env::current_account_id()).resolve_batch_claim_tip(tips_balances, payouts),
+		);
+	}
+
+	pub(crate) fn internal_resolve_claim_by_key(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		account_id: AccountId,
+		public_key: PublicKey,
+		amount: Balance,
+	) {
+		require!(env::promise_results_count() == 1);
+
+		let key = tips_balance_info.key();
+
+		match env::promise_result(0) {
+			PromiseResult::Successful(_) => {
+				self.reference_keys.remove(&key);
+				Promise::new(env::current_account_id()).delete_key(public_key);
+				self.claim_reference_by_key(&tips_balance_info, &account_id);
+
+				log!("{} created from {}", account_id, tips_balance_info.reference());
+			},
+			_ => {
+				if let Some(tips_balance) = self.tips_balances.get(&key) {
+					self.tips_balances.insert(&key, &tips_balance.add_balance(amount));
+				}
+
+				self.key_claims.insert(&public_key, &tips_balance_info);
+			},
+		}
+	}
+}
@@ -0,0 +1,6 @@
+pub mod calls;
+pub mod internal;
+pub mod private_calls;
+pub mod tests;
+pub mod types;
+pub mod views;
@@ -0,0 +1,15 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	#[private]
+	pub fn resolve_claim_by_key(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		account_id: AccountId,
+		public_key: PublicKey,
+		amount: Balance,
+	) {
+		self.internal_resolve_claim_by_key(tips_balance_info, account_id, public_key, amount);
+	}
+}
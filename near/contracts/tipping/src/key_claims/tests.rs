@@ -0,0 +1,318 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod key_claims_tests {
+	use crate::*;
+	use near_sdk::{
+		mock::VmAction,
+		test_utils::{accounts, get_created_receipts, VMContextBuilder},
+		testing_env, RuntimeFeesConfig, VMConfig,
+	};
+
+	const IMPLICIT_ACCOUNT: &str =
+		"98793cd91a3f870fb126f66285808c7e094afcfc4eda8a970f6648cdf0dbd6de";
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	fn claim_key() -> PublicKey {
+		"ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp".parse().unwrap()
+	}
+
+	fn claim_context(context: &mut VMContextBuilder) -> &mut VMContextBuilder {
+		context
+			.signer_account_id(accounts(0))
+			.predecessor_account_id(accounts(0))
+			.signer_account_pk(claim_key())
+			.attached_deposit(0)
+	}
+
+	#[test]
+	fn bind_reference_key_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to user_1
+		let user_1 = TipsBalanceInfo::new(&accounts(4), "user", "user_1", "native");
+
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(MIN_ACCOUNT_FUNDING)
+			.build());
+		contract.send_tip(user_1.clone(), None);
+
+		// Server binds user_1 to the claim key
+		testing_env!(context
+			.signer_account_id(accounts(4))
+			.predecessor_account_id(accounts(4))
+			.attached_deposit(CLAIM_KEY_ALLOWANCE)
+			.build());
+		contract.bind_reference_key(String::from("user"), String::from("user_1"), claim_key());
+
+		// Test
+		let reference_key =
+			contract.get_reference_key(accounts(4), String::from("user"), String::from("user_1"));
+
+		assert_eq!(reference_key, Some(claim_key()));
+		assert_eq!(contract.get_key_claim(claim_key()), Some(user_1));
+	}
+
+	#[test]
+	fn claim_by_key_creates_account() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to user_1
+		let user_1 = TipsBalanceInfo::new(&accounts(4), "user", "user_1", "native");
+
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(MIN_ACCOUNT_FUNDING)
+			.build());
+		contract.send_tip(user_1.clone(), None);
+
+		// Server binds user_1 to the claim key
+		testing_env!(context
+			.signer_account_id(accounts(4))
+			.predecessor_account_id(accounts(4))
+			.attached_deposit(CLAIM_KEY_ALLOWANCE)
+			.build());
+		contract.bind_reference_key(String::from("user"), String::from("user_1"), claim_key());
+		let account_id: AccountId = IMPLICIT_ACCOUNT.parse().unwrap();
+
+		// Recipient calls with the claim key
+		testing_env!(claim_context(&mut context).build());
+		contract.claim_by_key(account_id.clone(), None);
+
+		assert_eq!(contract.get_tips_balance(user_1.clone()).unwrap().tips_balance.get_amount(), 0);
+		assert!(contract.get_key_claim(claim_key()).is_none());
+
+		// Account is created
+		testing_env!(
+			context.build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![PromiseResult::Successful(vec![])],
+		);
+		contract.resolve_claim_by_key(
+			user_1.clone(),
+			account_id.clone(),
+			claim_key(),
+			MIN_ACCOUNT_FUNDING,
+		);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(user_1).unwrap().tips_balance;
+		let reference_key =
+			contract.get_reference_key(accounts(4), String::from("user"), String::from("user_1"));
+
+		assert_eq!(tips_balance.get_account_id(), &Some(account_id));
+		assert!(reference_key.is_none());
+	}
+
+	#[test]
+	fn failed_claim_by_key_restores_balance() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to user_1
+		let user_1 = TipsBalanceInfo::new(&accounts(4), "user", "user_1", "native");
+
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(MIN_ACCOUNT_FUNDING)
+			.build());
+		contract.send_tip(user_1.clone(), None);
+
+		// Server binds user_1 to the claim key
+		testing_env!(context
+			.signer_account_id(accounts(4))
+			.predecessor_account_id(accounts(4))
+			.attached_deposit(CLAIM_KEY_ALLOWANCE)
+			.build());
+		contract.bind_reference_key(String::from("user"), String::from("user_1"), claim_key());
+		let account_id: AccountId = format!("bob.{}", accounts(0)).parse().unwrap();
+
+		// Recipient calls with the claim key
+		testing_env!(claim_context(&mut context).build());
+		contract.claim_by_key(account_id.clone(), Some(claim_key()));
+
+		// Account creation fails
+		testing_env!(
+			context.build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![PromiseResult::Failed],
+		);
+		contract.resolve_claim_by_key(user_1.clone(), account_id, claim_key(), MIN_ACCOUNT_FUNDING);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(user_1.clone()).unwrap().tips_balance;
+
+		assert_eq!(tips_balance.get_amount(), MIN_ACCOUNT_FUNDING);
+		assert!(tips_balance.get_account_id().is_none());
+		assert_eq!(contract.get_key_claim(claim_key()), Some(user_1));
+	}
+
+	#[test]
+	#[should_panic(expected = "InvalidAccountId")]
+	fn cant_claim_by_key_for_other_named_account() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to user_1
+		let user_1 = TipsBalanceInfo::new(&accounts(4), "user", "user_1", "native");
+
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(MIN_ACCOUNT_FUNDING)
+			.build());
+		contract.send_tip(user_1.clone(), None);
+
+		// Server binds user_1 to the claim key
+		testing_env!(context
+			.signer_account_id(accounts(4))
+			.predecessor_account_id(accounts(4))
+			.attached_deposit(CLAIM_KEY_ALLOWANCE)
+			.build());
+		contract.bind_reference_key(String::from("user"), String::from("user_1"), claim_key());
+
+		// Test
+		testing_env!(claim_context(&mut context).build());
+		contract.claim_by_key("carol.near".parse().unwrap(), Some(claim_key()));
+	}
+
+	#[test]
+	#[should_panic(expected = "Unauthorized")]
+	fn cant_claim_by_key_from_other_account() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to user_1
+		let user_1 = TipsBalanceInfo::new(&accounts(4), "user", "user_1", "native");
+
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(MIN_ACCOUNT_FUNDING)
+			.build());
+		contract.send_tip(user_1.clone(), None);
+
+		// Server binds user_1 to the claim key
+		testing_env!(context
+			.signer_account_id(accounts(4))
+			.predecessor_account_id(accounts(4))
+			.attached_deposit(CLAIM_KEY_ALLOWANCE)
+			.build());
+		contract.bind_reference_key(String::from("user"), String::from("user_1"), claim_key());
+
+		// Test
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(0).build());
+		contract.claim_by_key(IMPLICIT_ACCOUNT.parse().unwrap(), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "InsufficientAttachedDeposit")]
+	fn cant_bind_reference_key_without_allowance() {
+		// Initialize contract
+		let mut context = get_context(accounts(4));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Test
+		testing_env!(context.attached_deposit(1).build());
+		contract.bind_reference_key(String::from("user"), String::from("user_1"), claim_key());
+	}
+
+	#[test]
+	fn unbind_reference_key_refunds_allowance() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to user_1
+		let user_1 = TipsBalanceInfo::new(&accounts(4), "user", "user_1", "native");
+
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(MIN_ACCOUNT_FUNDING)
+			.build());
+		contract.send_tip(user_1.clone(), None);
+
+		// Server binds user_1 to the claim key
+		testing_env!(context
+			.signer_account_id(accounts(4))
+			.predecessor_account_id(accounts(4))
+			.attached_deposit(CLAIM_KEY_ALLOWANCE)
+			.build());
+		contract.bind_reference_key(String::from("user"), String::from("user_1"), claim_key());
+
+		// Server unbinds user_1
+		testing_env!(context.attached_deposit(0).build());
+		contract.unbind_reference_key(String::from("user"), String::from("user_1"));
+
+		// Test
+		let refund = get_created_receipts().into_iter().find(|receipt| {
+			receipt.receiver_id == accounts(4) &&
+				receipt.actions == vec![VmAction::Transfer { deposit: CLAIM_KEY_ALLOWANCE }]
+		});
+
+		assert!(refund.is_some());
+		assert!(contract.get_key_claim(claim_key()).is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "ClaimingPaused")]
+	fn cant_claim_by_key_when_claiming_paused() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to user_1
+		let user_1 = TipsBalanceInfo::new(&accounts(4), "user", "user_1", "native");
+
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(MIN_ACCOUNT_FUNDING)
+			.build());
+		contract.send_tip(user_1.clone(), None);
+
+		// Server binds user_1 to the claim key
+		testing_env!(context
+			.signer_account_id(accounts(4))
+			.predecessor_account_id(accounts(4))
+			.attached_deposit(CLAIM_KEY_ALLOWANCE)
+			.build());
+		contract.bind_reference_key(String::from("user"), String::from("user_1"), claim_key());
+
+		// Owner pauses claiming
+		testing_env!(context
+			.signer_account_id(accounts(1))
+			.predecessor_account_id(accounts(1))
+			.attached_deposit(0)
+			.build());
+		contract.pause(PauseFeature::Claiming);
+
+		// Test
+		testing_env!(claim_context(&mut context).build());
+		contract.claim_by_key(IMPLICIT_ACCOUNT.parse().unwrap(), None);
+	}
+}
@@ -0,0 +1,7 @@
+use crate::*;
+
+/// Gas allowance of the access key added for a claim, paid by the server when it binds the key.
+pub const CLAIM_KEY_ALLOWANCE: Balance = 100_000_000_000_000_000_000_000;
+/// Smallest native balance that can fund a new account.
+pub const MIN_ACCOUNT_FUNDING: Balance = 10_000_000_000_000_000_000_000;
+pub const CLAIM_BY_KEY_METHOD: &str = "claim_by_key";
@@ -0,0 +1,19 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_reference_key(
+		&self,
+		server_id: ServerId,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+	) -> Option<PublicKey> {
+		let key = TipsBalanceKey::new(&server_id, &reference_type, &reference_id, "native");
+
+		self.reference_keys.get(&key)
+	}
+
+	pub fn get_key_claim(&self, public_key: PublicKey) -> Option<TipsBalanceInfo> {
+		self.key_claims.get(&public_key)
+	}
+}
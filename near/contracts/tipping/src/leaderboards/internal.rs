@@ -0,0 +1,91 @@
+use crate::*;
+
+impl Tipping {
+	/// Adds a tip to the sender's total on the reference and refreshes both leaderboards.
+	pub(crate) fn update_leaderboards(
+		&mut self,
+		sender: &AccountId,
+		tips_balance: &TipsBalance,
+		amount: Balance,
+	) {
+		let key = tips_balance.key();
+		let supporter_key = (key.clone(), sender.clone());
+		let total = self.supporter_totals.get(&supporter_key).unwrap_or_default() + amount;
+
+		self.supporter_totals.insert(&supporter_key, &total);
+		self.update_top_supporters(&key, sender, total);
+		self.update_top_references(tips_balance);
+	}
+
+	/// Takes back part of the sender's total after a tip is returned to them.
+	pub(crate) fn reduce_supporter_total(
+		&mut self,
+		key: &TipsBalanceKey,
+		sender: &AccountId,
+		amount: Balance,
+	) {
+		let supporter_key = (key.clone(), sender.clone());
+		let total = self.supporter_totals.get(&supporter_key).unwrap_or_default();
+		let total = total.saturating_sub(amount);
+
+		if total.is_zero() {
+			self.supporter_totals.remove(&supporter_key);
+		} else {
+			self.supporter_totals.insert(&supporter_key, &total);
+		}
+
+		self.update_top_supporters(key, sender, total);
+	}
+
+	pub(crate) fn update_top_supporters(
+		&mut self,
+		key: &TipsBalanceKey,
+		sender: &AccountId,
+		total: Balance,
+	) {
+		let mut top_supporters = self.top_supporters.get(key).unwrap_or_default();
+
+		top_supporters.retain(|supporter| supporter.get_account_id() != sender);
+
+		if !total.is_zero() {
+			top_supporters.push(Supporter::new(sender, total));
+		}
+
+		top_supporters.sort_by_key(|supporter| Reverse(supporter.get_amount()));
+		top_supporters.truncate(MAX_TOP_SUPPORTERS);
+
+		if top_supporters.is_empty() {
+			self.top_supporters.remove(key);
+		} else {
+			self.top_supporters.insert(key, &top_supporters);
+		}
+	}
+
+	/// Ranks a reference on its server by the lifetime total of the balance.
+	pub(crate) fn update_top_references(&mut self, tips_balance: &TipsBalance) {
+		let tips_balance_info = tips_balance.get_tips_balance_info();
+		let server_id = tips_balance_info.get_server_id().clone();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let reference_type = tips_balance.get_reference_type();
+		let reference_id = tips_balance.get_reference_id();
+		let total = tips_balance.get_total_amount();
+		let top_references_key = (server_id, ft_identifier);
+		let mut top_references = self.top_references.get(&top_references_key).unwrap_or_default();
+
+		top_references
+			.retain(|top_reference| !top_reference.is_reference(reference_type, reference_id));
+
+		if !total.is_zero() {
+			top_references.push(TopReference::new(reference_type, reference_id, total));
+		}
+
+		top_references.sort_by_key(|top_reference| Reverse(top_reference.get_amount()));
+		top_references.truncate(MAX_TOP_REFERENCES);
+
+		if top_references.is_empty() {
+			self.top_references.remove(&top_references_key);
+		} else {
+			self.top_references.insert(&top_references_key, &top_references);
+		}
+	}
+}
@@ -0,0 +1,4 @@
+pub mod internal;
+pub mod tests;
+pub mod types;
+pub mod views;
@@ -0,0 +1,74 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod leaderboards_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn send_tip_updates_top_supporters() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+		let tip: Balance = 100000000000000000000000; // 0.1 NEAR
+
+		// Send tips to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(tip).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(tip).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		let top_supporters = contract.get_top_supporters(tips_balance_info.clone());
+		let supporter_total = contract.get_supporter_total(tips_balance_info, accounts(2)).unwrap();
+
+		assert_eq!(top_supporters.len(), 2);
+		assert_eq!(top_supporters[0].supporter, Supporter::new(&accounts(3), tip * 2));
+		assert_eq!(top_supporters[0].formatted_amount, String::from("0.2"));
+		assert_eq!(top_supporters[1].supporter, Supporter::new(&accounts(2), tip));
+		assert_eq!(supporter_total.formatted_amount, String::from("0.1"));
+	}
+
+	#[test]
+	fn send_tip_updates_top_references() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+
+		// Send tips to post_1 and post_2
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+		contract.send_tip(post_1, None);
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(30).build());
+		contract.send_tip(post_2, None);
+
+		// Test
+		let top_references = contract.get_top_references(accounts(4), String::from("native"));
+
+		assert_eq!(top_references.len(), 2);
+		assert_eq!(top_references[0].top_reference, TopReference::new("post", "post_2", 30));
+		assert_eq!(top_references[1].top_reference, TopReference::new("post", "post_1", 10));
+	}
+}
@@ -0,0 +1,76 @@
+use crate::*;
+
+pub const MAX_TOP_SUPPORTERS: usize = 10;
+pub const MAX_TOP_REFERENCES: usize = 10;
+
+pub type SupporterKey = (TipsBalanceKey, AccountId);
+pub type TopReferencesKey = (ServerId, FtIdentifier);
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Supporter {
+	account_id: AccountId,
+	amount: Balance,
+}
+impl Supporter {
+	pub fn new(account_id: &AccountId, amount: Balance) -> Self {
+		Self { account_id: account_id.clone(), amount }
+	}
+
+	pub fn get_account_id(&self) -> &AccountId {
+		&self.account_id
+	}
+
+	pub fn get_amount(&self) -> Balance {
+		self.amount
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TopReference {
+	reference_type: ReferenceType,
+	reference_id: ReferenceId,
+	amount: Balance,
+}
+impl TopReference {
+	pub fn new(reference_type: &str, reference_id: &str, amount: Balance) -> Self {
+		Self {
+			reference_type: reference_type.to_string(),
+			reference_id: reference_id.to_string(),
+			amount,
+		}
+	}
+
+	pub fn get_reference_type(&self) -> &str {
+		&self.reference_type
+	}
+
+	pub fn get_reference_id(&self) -> &str {
+		&self.reference_id
+	}
+
+	pub fn get_amount(&self) -> Balance {
+		self.amount
+	}
+
+	pub fn is_reference(&self, reference_type: &str, reference_id: &str) -> bool {
+		self.reference_type == reference_type && self.reference_id == reference_id
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SupporterWithFormattedAmount {
+	pub supporter: Supporter,
+	pub symbol: String,
+	pub formatted_amount: String,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TopReferenceWithFormattedAmount {
+	pub top_reference: TopReference,
+	pub symbol: String,
+	pub formatted_amount: String,
+}
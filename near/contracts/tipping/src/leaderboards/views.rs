@@ -0,0 +1,55 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_top_supporters(
+		&self,
+		tips_balance_info: TipsBalanceInfo,
+	) -> Vec<SupporterWithFormattedAmount> {
+		let ft_identifier = tips_balance_info.get_ft_identifier();
+		let top_supporters = self.top_supporters.get(&tips_balance_info.key()).unwrap_or_default();
+
+		top_supporters
+			.into_iter()
+			.map(|supporter| {
+				let (symbol, formatted_amount) =
+					self.formatted_amount_of(ft_identifier, supporter.get_amount());
+
+				SupporterWithFormattedAmount { supporter, symbol, formatted_amount }
+			})
+			.collect()
+	}
+
+	pub fn get_supporter_total(
+		&self,
+		tips_balance_info: TipsBalanceInfo,
+		sender: AccountId,
+	) -> Option<SupporterWithFormattedAmount> {
+		let ft_identifier = tips_balance_info.get_ft_identifier();
+		let supporter_key = (tips_balance_info.key(), sender.clone());
+		let amount = self.supporter_totals.get(&supporter_key)?;
+		let (symbol, formatted_amount) = self.formatted_amount_of(ft_identifier, amount);
+		let supporter = Supporter::new(&sender, amount);
+
+		Some(SupporterWithFormattedAmount { supporter, symbol, formatted_amount })
+	}
+
+	pub fn get_top_references(
+		&self,
+		server_id: ServerId,
+		ft_identifier: FtIdentifier,
+	) -> Vec<TopReferenceWithFormattedAmount> {
+		let top_references_key = (server_id, ft_identifier.clone());
+		let top_references = self.top_references.get(&top_references_key).unwrap_or_default();
+
+		top_references
+			.into_iter()
+			.map(|top_reference| {
+				let (symbol, formatted_amount) =
+					self.formatted_amount_of(&ft_identifier, top_reference.get_amount());
+
+				TopReferenceWithFormattedAmount { top_reference, symbol, formatted_amount }
+			})
+			.collect()
+	}
+}
@@ -1,25 +1,66 @@
+mod allowances;
+mod auto_forward;
+mod bounties;
+mod campaigns;
+mod conditional_tips;
+mod deposits;
 mod external;
 mod ft_callback;
+mod funding_rounds;
+mod gift_codes;
 mod internal;
+mod key_claims;
+mod leaderboards;
+mod matching_pools;
+mod migration;
+mod moderation;
+mod pause;
+mod rate_limits;
+mod refunds;
+mod revenue_splits;
+mod streams;
+mod subscriptions;
+mod tip_records;
 mod tips_balance;
 mod types;
+mod vouchers;
 
+use allowances::types::*;
+use bounties::types::*;
+use campaigns::types::*;
+use conditional_tips::types::*;
+use deposits::types::*;
 use external::*;
+use funding_rounds::types::*;
+use gift_codes::types::*;
+use key_claims::types::*;
+use leaderboards::types::*;
+use matching_pools::types::*;
+use moderation::types::*;
 use near_sdk::{
 	assert_one_yocto,
 	borsh::{self, BorshDeserialize, BorshSerialize},
-	collections::UnorderedMap,
+	collections::{LookupMap, LookupSet, UnorderedMap, UnorderedSet},
 	env, ext_contract,
-	json_types::{Base64VecU8, U128},
+	json_types::{Base58CryptoHash, Base64VecU8, U128},
 	log, near_bindgen, require,
 	serde::{Deserialize, Serialize},
 	serde_json::{from_slice, from_str},
-	AccountId, Balance, BorshStorageKey, PanicOnDefault, Promise, PromiseOrValue, PromiseResult,
+	AccountId, Balance, BorshStorageKey, CryptoHash, CurveType, PanicOnDefault, Promise,
+	PromiseOrValue, PromiseResult, PublicKey, Timestamp,
 };
 use num_traits::Zero;
-use std::cmp::min;
+use pause::types::*;
+use rate_limits::types::*;
+use refunds::types::*;
+use revenue_splits::types::*;
+use std::cmp::{max, min, Reverse};
+use streams::types::*;
+use subscriptions::types::*;
+use tip_records::types::*;
 use tips_balance::types::*;
 use types::*;
+use vouchers::types::*;
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
@@ -27,6 +68,62 @@ pub struct Tipping {
 	pub owner: AccountId,
 	pub tips_balances: UnorderedMap<TipsBalanceKey, TipsBalance>,
 	pub ft_identifiers: UnorderedMap<FtIdentifier, FtIdentifierInfo>,
+	pub tip_records: LookupMap<TipId, TipRecord>,
+	pub tip_record_queues: LookupMap<TipRecordIndex, TipRecordQueue>,
+	pub tip_record_ids: LookupMap<(TipRecordIndex, u64), TipId>,
+	pub tip_record_config: TipRecordConfig,
+	pub supporter_totals: LookupMap<SupporterKey, Balance>,
+	pub top_supporters: LookupMap<TipsBalanceKey, Vec<Supporter>>,
+	pub top_references: LookupMap<TopReferencesKey, Vec<TopReference>>,
+	pub refund_expiry_days: LookupMap<ServerId, u64>,
+	pub contribution_pools: LookupMap<TipsBalanceKey, ContributionPool>,
+	pub contributions: LookupMap<ContributionKey, Contribution>,
+	pub pause_state: PauseState,
+	pub paused_tokens: UnorderedSet<FtIdentifier>,
+	pub pausers: UnorderedSet<AccountId>,
+	pub tip_limits: LookupMap<FtIdentifier, TipLimit>,
+	pub moderators: UnorderedSet<AccountId>,
+	pub blocked_references: UnorderedMap<ReferenceKey, BlockInfo>,
+	pub blocked_senders: UnorderedMap<AccountId, BlockInfo>,
+	pub rate_limits: LookupMap<RateLimitKey, RateLimit>,
+	pub rate_limit_exemptions: UnorderedSet<AccountId>,
+	pub rate_windows: LookupMap<RateWindowKey, RateWindow>,
+	pub revenue_splits: LookupMap<ReferenceKey, RevenueSplit>,
+	pub auto_forward_accounts: UnorderedSet<AccountId>,
+	pub deposits: LookupMap<DepositKey, Balance>,
+	pub allowances: LookupMap<AllowanceKey, Allowance>,
+	pub voucher_keys: LookupMap<AccountId, PublicKey>,
+	pub voucher_states: LookupMap<VoucherStateKey, VoucherState>,
+	pub subscriptions: UnorderedMap<SubscriptionId, Subscription>,
+	pub subscription_ids: LookupMap<SubscriptionIndex, Vec<SubscriptionId>>,
+	pub subscription_config: SubscriptionConfig,
+	pub streams: LookupMap<StreamId, Stream>,
+	pub stream_ids: LookupMap<StreamIndex, Vec<StreamId>>,
+	pub next_stream_id: StreamId,
+	pub conditional_tips: UnorderedMap<ConditionalTipId, ConditionalTip>,
+	pub conditional_tip_ids: LookupMap<AccountId, Vec<ConditionalTipId>>,
+	pub next_conditional_tip_id: ConditionalTipId,
+	pub campaigns: LookupMap<TipsBalanceKey, Campaign>,
+	pub campaign_contributions: LookupMap<ContributionKey, Contribution>,
+	pub matching_pools: LookupMap<MatchingPoolId, MatchingPool>,
+	pub matching_pool_ids: LookupMap<FtIdentifier, Vec<MatchingPoolId>>,
+	pub matched_amounts: LookupMap<MatchedKey, Balance>,
+	pub next_matching_pool_id: MatchingPoolId,
+	pub funding_rounds: LookupMap<FundingRoundId, FundingRound>,
+	pub funding_round_ids: LookupMap<TipsBalanceKey, Vec<FundingRoundId>>,
+	pub round_references: LookupMap<RoundReferenceKey, RoundReference>,
+	pub round_contributions: LookupMap<RoundContributionKey, Balance>,
+	pub next_funding_round_id: FundingRoundId,
+	pub bounties: LookupMap<BountyId, Bounty>,
+	pub bounty_ids: LookupMap<BountyIndex, Vec<BountyId>>,
+	pub next_bounty_id: BountyId,
+	pub gift_codes: LookupMap<Base58CryptoHash, GiftCode>,
+	pub gift_code_hashes: LookupMap<AccountId, Vec<Base58CryptoHash>>,
+	pub gift_code_commitments: LookupMap<AccountId, GiftCodeCommitment>,
+	pub revealed_gift_codes: LookupSet<Base58CryptoHash>,
+	pub reference_keys: LookupMap<TipsBalanceKey, PublicKey>,
+	pub key_claims: LookupMap<PublicKey, TipsBalanceInfo>,
+	pub tips_balances_migration: Option<u64>,
 }
 
 #[near_bindgen]
@@ -39,6 +136,62 @@ impl Tipping {
 			owner: env::signer_account_id(),
 			tips_balances: UnorderedMap::new(StorageKeys::TipsBalance),
 			ft_identifiers: UnorderedMap::new(StorageKeys::FtIdentifierInfo),
+			tip_records: LookupMap::new(StorageKeys::TipRecord),
+			tip_record_queues: LookupMap::new(StorageKeys::TipRecordQueue),
+			tip_record_ids: LookupMap::new(StorageKeys::TipRecordId),
+			tip_record_config: TipRecordConfig::default(),
+			supporter_totals: LookupMap::new(StorageKeys::SupporterTotal),
+			top_supporters: LookupMap::new(StorageKeys::TopSupporters),
+			top_references: LookupMap::new(StorageKeys::TopReferences),
+			refund_expiry_days: LookupMap::new(StorageKeys::RefundExpiryDays),
+			contribution_pools: LookupMap::new(StorageKeys::ContributionPool),
+			contributions: LookupMap::new(StorageKeys::Contribution),
+			pause_state: PauseState::default(),
+			paused_tokens: UnorderedSet::new(StorageKeys::PausedTokens),
+			pausers: UnorderedSet::new(StorageKeys::Pausers),
+			tip_limits: LookupMap::new(StorageKeys::TipLimit),
+			moderators: UnorderedSet::new(StorageKeys::Moderators),
+			blocked_references: UnorderedMap::new(StorageKeys::BlockedReferences),
+			blocked_senders: UnorderedMap::new(StorageKeys::BlockedSenders),
+			rate_limits: LookupMap::new(StorageKeys::RateLimits),
+			rate_limit_exemptions: UnorderedSet::new(StorageKeys::RateLimitExemptions),
+			rate_windows: LookupMap::new(StorageKeys::RateWindows),
+			revenue_splits: LookupMap::new(StorageKeys::RevenueSplits),
+			auto_forward_accounts: UnorderedSet::new(StorageKeys::AutoForwardAccounts),
+			deposits: LookupMap::new(StorageKeys::Deposits),
+			allowances: LookupMap::new(StorageKeys::Allowances),
+			voucher_keys: LookupMap::new(StorageKeys::VoucherKeys),
+			voucher_states: LookupMap::new(StorageKeys::VoucherStates),
+			subscriptions: UnorderedMap::new(StorageKeys::Subscriptions),
+			subscription_ids: LookupMap::new(StorageKeys::SubscriptionIds),
+			subscription_config: SubscriptionConfig::default(),
+			streams: LookupMap::new(StorageKeys::Streams),
+			stream_ids: LookupMap::new(StorageKeys::StreamIds),
+			next_stream_id: 0,
+			conditional_tips: UnorderedMap::new(StorageKeys::ConditionalTips),
+			conditional_tip_ids: LookupMap::new(StorageKeys::ConditionalTipIds),
+			next_conditional_tip_id: 0,
+			campaigns: LookupMap::new(StorageKeys::Campaigns),
+			campaign_contributions: LookupMap::new(StorageKeys::CampaignContributions),
+			matching_pools: LookupMap::new(StorageKeys::MatchingPools),
+			matching_pool_ids: LookupMap::new(StorageKeys::MatchingPoolIds),
+			matched_amounts: LookupMap::new(StorageKeys::MatchedAmounts),
+			next_matching_pool_id: 0,
+			funding_rounds: LookupMap::new(StorageKeys::FundingRounds),
+			funding_round_ids: LookupMap::new(StorageKeys::FundingRoundIds),
+			round_references: LookupMap::new(StorageKeys::RoundReferences),
+			round_contributions: LookupMap::new(StorageKeys::RoundContributions),
+			next_funding_round_id: 0,
+			bounties: LookupMap::new(StorageKeys::Bounties),
+			bounty_ids: LookupMap::new(StorageKeys::BountyIds),
+			next_bounty_id: 0,
+			gift_codes: LookupMap::new(StorageKeys::GiftCodes),
+			gift_code_hashes: LookupMap::new(StorageKeys::GiftCodeHashes),
+			gift_code_commitments: LookupMap::new(StorageKeys::GiftCodeCommitments),
+			revealed_gift_codes: LookupSet::new(StorageKeys::RevealedGiftCodes),
+			reference_keys: LookupMap::new(StorageKeys::ReferenceKeys),
+			key_claims: LookupMap::new(StorageKeys::KeyClaims),
+			tips_balances_migration: None,
 		};
 
 		let near = FtIdentifierInfo::new("native", 24, "NEAR");
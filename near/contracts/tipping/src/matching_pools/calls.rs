@@ -0,0 +1,86 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Moves an amount of the deposit of the caller into a pool that matches tips to eligible
+	/// references.
+	#[payable]
+	#[allow(clippy::too_many_arguments)]
+	pub fn create_matching_pool(
+		&mut self,
+		ft_identifier: FtIdentifier,
+		amount: U128,
+		server_ids: Vec<ServerId>,
+		reference_types: Vec<ReferenceType>,
+		match_ratio: u16,
+		reference_cap: U128,
+		starts_at: Option<Timestamp>,
+		ends_at: Timestamp,
+	) -> MatchingPoolId {
+		let sponsor = env::predecessor_account_id();
+		let now = env::block_timestamp();
+		let starts_at = starts_at.unwrap_or(now);
+
+		assert!(amount.0 > 0, "NothingToDeposit");
+		assert!(match_ratio > 0, "InvalidMatchRatio");
+		assert!(reference_cap.0 > 0, "InvalidReferenceCap");
+		assert!(starts_at < ends_at && ends_at > now, "InvalidMatchingWindow");
+		assert!(server_ids.len() <= MAX_ELIGIBLE_ENTRIES, "TooManyEligibleEntries");
+		assert!(reference_types.len() <= MAX_ELIGIBLE_ENTRIES, "TooManyEligibleEntries");
+		assert!(self.ft_identifiers.get(&ft_identifier).is_some(), "TokenNotFound");
+		assert!(
+			self.matching_pool_ids_of(&ft_identifier).len() < MAX_MATCHING_POOLS_PER_TOKEN,
+			"TooManyMatchingPools"
+		);
+		self.assert_not_paused(PauseFeature::Tipping, Some(&ft_identifier));
+
+		self.add_attached_deposit(&sponsor);
+
+		self.remove_deposit(&sponsor, &ft_identifier, amount.0);
+
+		let id = self.next_matching_pool_id;
+		let matching_pool = MatchingPool::new(id, &sponsor, &ft_identifier, amount.0)
+			.set_eligibility(server_ids, reference_types)
+			.set_match_ratio(match_ratio)
+			.set_reference_cap(reference_cap.0)
+			.set_window(starts_at, ends_at);
+		let mut ids = self.matching_pool_ids_of(&ft_identifier);
+
+		ids.push(id);
+
+		self.next_matching_pool_id += 1;
+		self.matching_pools.insert(&id, &matching_pool);
+		self.matching_pool_ids.insert(&ft_identifier, &ids);
+
+		log!("{} created matching pool {}", sponsor, id);
+
+		id
+	}
+
+	/// Removes a pool and puts what is left back in the deposit of the sponsor. The sponsor can
+	/// close it at any time, anyone else once it ended.
+	pub fn close_matching_pool(&mut self, matching_pool_id: MatchingPoolId) -> U128 {
+		let matching_pool =
+			self.matching_pools.get(&matching_pool_id).expect("MatchingPoolNotExists");
+		let sponsor = matching_pool.get_sponsor().clone();
+		let ft_identifier = matching_pool.get_ft_identifier().to_string();
+		let remainder = matching_pool.get_amount();
+
+		assert!(
+			env::predecessor_account_id() == sponsor ||
+				env::block_timestamp() >= matching_pool.get_ends_at(),
+			"Unauthorized"
+		);
+		self.assert_not_paused(PauseFeature::Claiming, Some(&ft_identifier));
+
+		self.remove_matching_pool(&matching_pool);
+
+		if remainder > 0 {
+			self.add_deposit(&sponsor, &ft_identifier, remainder);
+		}
+
+		log!("Matching pool {} closed", matching_pool_id);
+
+		U128(remainder)
+	}
+}
@@ -0,0 +1,139 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn matching_pool_ids_of(&self, ft_identifier: &str) -> Vec<MatchingPoolId> {
+		self.matching_pool_ids.get(&ft_identifier.to_string()).unwrap_or_default()
+	}
+
+	pub(crate) fn matched_amount_of(&self, id: MatchingPoolId, key: &TipsBalanceKey) -> Balance {
+		self.matched_amounts.get(&(id, key.clone())).unwrap_or_default()
+	}
+
+	pub(crate) fn remove_matching_pool(&mut self, matching_pool: &MatchingPool) {
+		let id = matching_pool.get_id();
+		let ft_identifier = matching_pool.get_ft_identifier().to_string();
+		let mut ids = self.matching_pool_ids_of(&ft_identifier);
+
+		ids.retain(|matching_pool_id| *matching_pool_id != id);
+
+		if ids.is_empty() {
+			self.matching_pool_ids.remove(&ft_identifier);
+		} else {
+			self.matching_pool_ids.insert(&ft_identifier, &ids);
+		}
+
+		self.matching_pools.remove(&id);
+	}
+
+	/// Adds the matched amounts of every active pool to the `TipsBalance` that was just tipped. The
+	/// match is credited as a tip from the sponsor and an exhausted pool is removed.
+	pub(crate) fn match_tip(
+		&mut self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+		tip_id: TipId,
+	) {
+		let key = tips_balance_info.key();
+		let now = env::block_timestamp();
+		let ft_identifier = tips_balance_info.get_ft_identifier();
+		let mut matches = Vec::new();
+
+		for id in self.matching_pool_ids_of(ft_identifier) {
+			let matching_pool = match self.matching_pools.get(&id) {
+				Some(matching_pool) => matching_pool,
+				None => continue,
+			};
+
+			if !matching_pool.is_active(now) || !matching_pool.is_eligible(tips_balance_info) {
+				continue
+			}
+
+			if matching_pool.get_sponsor() == sender {
+				continue
+			}
+
+			let ratio = matching_pool.get_match_ratio() as Balance;
+			let matched_amount = self.matched_amount_of(id, &key);
+			let reference_left = matching_pool.get_reference_cap().saturating_sub(matched_amount);
+			let matched = self.proportion(amount, ratio, TOTAL_SPLIT_WEIGHT as Balance);
+			let matched = min(min(matched, reference_left), matching_pool.get_amount());
+
+			if matched.is_zero() {
+				continue
+			}
+
+			let sponsor = matching_pool.get_sponsor().clone();
+			let matching_pool = matching_pool.spend(matched);
+
+			if matching_pool.get_amount().is_zero() {
+				self.remove_matching_pool(&matching_pool);
+			} else {
+				self.matching_pools.insert(&id, &matching_pool);
+			}
+
+			self.matched_amounts.insert(&(id, key.clone()), &(matched_amount + matched));
+
+			let match_tip_id = self.create_tips_balance(
+				&sponsor,
+				tips_balance_info,
+				&matched,
+				None,
+				TipKind::Credit,
+			);
+			let (symbol, formatted) = self.formatted_amount_of(ft_identifier, matched);
+
+			matches.push(TipMatch {
+				matching_pool_id: id,
+				sponsor: sponsor.clone(),
+				tip_id: match_tip_id,
+				amount: matched,
+			});
+
+			log!(
+				"{} matched {} {} to {}",
+				sponsor,
+				formatted,
+				symbol,
+				tips_balance_info.reference()
+			);
+		}
+
+		if !matches.is_empty() {
+			self.update_tip_record(tip_id, |tip_record| tip_record.set_matches(matches));
+		}
+	}
+
+	/// Takes the matches of a cancelled tip back out of its balance and returns them to their
+	/// pools, or to the deposit of the sponsor once a pool is closed.
+	pub(crate) fn rollback_tip_matches(&mut self, tip_record: &TipRecord) {
+		let key = tip_record.get_key();
+		let ft_identifier = tip_record.get_ft_identifier();
+
+		for tip_match in tip_record.get_matches() {
+			let id = tip_match.matching_pool_id;
+			let matched_key = (id, key.clone());
+			let matched_amount = self.matched_amount_of(id, key).saturating_sub(tip_match.amount);
+
+			// Match records are newer than the tip they match, so they are never pruned first.
+			if let Some(match_record) = self.tip_records.get(&tip_match.tip_id) {
+				self.apply_tip_cancellation(&match_record, true);
+			}
+
+			if matched_amount.is_zero() {
+				self.matched_amounts.remove(&matched_key);
+			} else {
+				self.matched_amounts.insert(&matched_key, &matched_amount);
+			}
+
+			match self.matching_pools.get(&id) {
+				Some(matching_pool) =>
+					self.matching_pools.insert(&id, &matching_pool.unspend(tip_match.amount)),
+				None => {
+					self.add_deposit(&tip_match.sponsor, ft_identifier, tip_match.amount);
+					None
+				},
+			};
+		}
+	}
+}
@@ -0,0 +1,5 @@
+pub mod calls;
+pub mod internal;
+pub mod tests;
+pub mod types;
+pub mod views;
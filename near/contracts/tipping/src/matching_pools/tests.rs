@@ -0,0 +1,387 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod matching_pools_tests {
+	use crate::*;
+	use near_sdk::{
+		serde_json::to_vec,
+		test_utils::{accounts, VMContextBuilder},
+		testing_env, RuntimeFeesConfig, VMConfig,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn send_tip_is_matched() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(5) matches posts on accounts(4) 1:1, up to 30 per post
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(100).build());
+		let id = contract.create_matching_pool(
+			String::from("native"),
+			U128(100),
+			vec![accounts(4)],
+			vec![String::from("post")],
+			10000,
+			U128(30),
+			None,
+			1000,
+		);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Send tip to post_1
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(20).build());
+		contract.send_tip(post_1.clone(), None);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(post_1.clone()).unwrap();
+		let matching_pool = contract.get_matching_pool(id).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 40);
+		assert_eq!(matching_pool.get_amount(), 80);
+		assert_eq!(matching_pool.get_matched(), 20);
+		assert_eq!(contract.get_matched_amount(id, post_1).0, 20);
+	}
+
+	#[test]
+	fn cancel_tip_rolls_back_match() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(5) matches posts on accounts(4) 1:1, up to 30 per post
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(100).build());
+		let id = contract.create_matching_pool(
+			String::from("native"),
+			U128(100),
+			vec![accounts(4)],
+			vec![String::from("post")],
+			10000,
+			U128(30),
+			None,
+			1000,
+		);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Send tip to post_1
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(20).build());
+		let tip_id = contract.send_tip(post_1.clone(), None);
+
+		// Cancel the tip
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(post_1.clone()).unwrap();
+		let matching_pool = contract.get_matching_pool(id).unwrap();
+		let match_record = &contract.get_tip_records_by_sender(accounts(5), None, None).data[0];
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 0);
+		assert_eq!(matching_pool.get_amount(), 100);
+		assert_eq!(matching_pool.get_matched(), 0);
+		assert_eq!(contract.get_matched_amount(id, post_1).0, 0);
+		assert!(match_record.tip_record.is_cancelled());
+	}
+
+	#[test]
+	fn cancel_tip_returns_match_of_closed_pool_to_sponsor() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(5) matches posts on accounts(4) 1:1, up to 30 per post
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(100).build());
+		let id = contract.create_matching_pool(
+			String::from("native"),
+			U128(100),
+			vec![accounts(4)],
+			vec![String::from("post")],
+			10000,
+			U128(30),
+			None,
+			1000,
+		);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Send tip to post_1 and close the pool
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(20).build());
+		let tip_id = contract.send_tip(post_1.clone(), None);
+
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(0).build());
+		contract.close_matching_pool(id);
+
+		// Cancel the tip
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(post_1).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 0);
+		assert_eq!(contract.get_deposit(accounts(5), String::from("native")).0, 100);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipNotCancellable")]
+	fn cant_cancel_match() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(5) matches posts on accounts(4) 1:1, up to 30 per post
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(100).build());
+		contract.create_matching_pool(
+			String::from("native"),
+			U128(100),
+			vec![accounts(4)],
+			vec![String::from("post")],
+			10000,
+			U128(30),
+			None,
+			1000,
+		);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Send tip to post_1
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(20).build());
+		contract.send_tip(post_1, None);
+
+		// Test
+		let match_record = &contract.get_tip_records_by_sender(accounts(5), None, None).data[0];
+		let match_id = match_record.tip_record.get_id();
+
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(1).build());
+		contract.cancel_tip(match_id);
+	}
+
+	#[test]
+	fn match_stops_at_reference_cap() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(5) matches posts on accounts(4) 1:1, up to 30 per post
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(100).build());
+		let id = contract.create_matching_pool(
+			String::from("native"),
+			U128(100),
+			vec![accounts(4)],
+			vec![String::from("post")],
+			10000,
+			U128(30),
+			None,
+			1000,
+		);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Send two tips to post_1
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(20).build());
+		contract.send_tip(post_1.clone(), None);
+		contract.send_tip(post_1.clone(), None);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(post_1.clone()).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 70);
+		assert_eq!(contract.get_matched_amount(id, post_1).0, 30);
+	}
+
+	#[test]
+	fn ineligible_tips_are_not_matched() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(5) matches posts on accounts(4) 1:1, up to 30 per post
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(100).build());
+		let id = contract.create_matching_pool(
+			String::from("native"),
+			U128(100),
+			vec![accounts(4)],
+			vec![String::from("post")],
+			10000,
+			U128(30),
+			None,
+			1000,
+		);
+
+		// Payload
+		let comment_1 = TipsBalanceInfo::new(&accounts(4), "comment", "comment_1", "native");
+		let post_1 = TipsBalanceInfo::new(&accounts(3), "post", "post_1", "native");
+		let tips = vec![(comment_1.clone(), U128(10)), (post_1.clone(), U128(10))];
+
+		// Send tips outside of the eligible server and reference types
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(20).build());
+		contract.send_tips(tips, None);
+
+		// Test
+		assert_eq!(contract.get_tips_balance(comment_1).unwrap().tips_balance.get_amount(), 10);
+		assert_eq!(contract.get_tips_balance(post_1).unwrap().tips_balance.get_amount(), 10);
+		assert_eq!(contract.get_matching_pool(id).unwrap().get_amount(), 100);
+	}
+
+	#[test]
+	fn ft_tip_is_matched() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		let ft_identifier = String::from("ft.near");
+
+		contract.create_ft_identifier(&ft_identifier, "FT", &18);
+		contract.add_deposit(&accounts(5), &ft_identifier, 100);
+
+		// accounts(5) matches every reference 2:1, up to 50 per reference
+		testing_env!(context.predecessor_account_id(accounts(5)).build());
+		let id = contract.create_matching_pool(
+			ft_identifier.clone(),
+			U128(100),
+			vec![],
+			vec![],
+			5000,
+			U128(50),
+			None,
+			1000,
+		);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", &ft_identifier);
+		let ft_metadata = FungibleTokenMetadata {
+			spec: String::from("ft-1.0.0"),
+			name: String::from("Fungible Token"),
+			symbol: String::from("FT"),
+			icon: None,
+			reference: None,
+			reference_hash: None,
+			decimals: 18,
+		};
+
+		// Resolve a fungible token tip
+		testing_env!(
+			context.predecessor_account_id(accounts(0)).build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![PromiseResult::Successful(to_vec(&ft_metadata).unwrap())],
+		);
+		contract.resolve_send_tip(accounts(3), post_1.clone(), 40, None);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(post_1).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 60);
+		assert_eq!(contract.get_matching_pool(id).unwrap().get_amount(), 80);
+	}
+
+	#[test]
+	fn close_matching_pool_returns_remainder() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(5) matches posts on accounts(4) 1:1, up to 30 per post
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(100).build());
+		let id = contract.create_matching_pool(
+			String::from("native"),
+			U128(100),
+			vec![accounts(4)],
+			vec![String::from("post")],
+			10000,
+			U128(30),
+			None,
+			1000,
+		);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Send tip to post_1
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(20).build());
+		contract.send_tip(post_1, None);
+
+		// Anyone can close the pool once it ended
+		testing_env!(context.block_timestamp(1000).attached_deposit(0).build());
+		let remainder = contract.close_matching_pool(id);
+
+		// Test
+		let deposit = contract.get_deposit(accounts(5), String::from("native"));
+
+		assert_eq!(remainder.0, 80);
+		assert_eq!(deposit.0, 80);
+		assert!(contract.get_matching_pool(id).is_none());
+		assert!(contract.get_matching_pools(String::from("native")).is_empty());
+	}
+
+	#[test]
+	#[should_panic(expected = "Unauthorized")]
+	fn cant_close_active_matching_pool_when_not_sponsor() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(5) matches posts on accounts(4) 1:1, up to 30 per post
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(100).build());
+		let id = contract.create_matching_pool(
+			String::from("native"),
+			U128(100),
+			vec![accounts(4)],
+			vec![String::from("post")],
+			10000,
+			U128(30),
+			None,
+			1000,
+		);
+
+		// Test
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.close_matching_pool(id);
+	}
+
+	#[test]
+	#[should_panic(expected = "InvalidMatchingWindow")]
+	fn cant_create_matching_pool_with_past_window() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.block_timestamp(2000).build());
+		let mut contract = Tipping::new(None);
+
+		// Test
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(100).build());
+		contract.create_matching_pool(
+			String::from("native"),
+			U128(100),
+			vec![],
+			vec![],
+			10000,
+			U128(30),
+			None,
+			1000,
+		);
+	}
+}
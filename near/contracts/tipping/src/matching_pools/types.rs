@@ -0,0 +1,142 @@
+use crate::*;
+
+pub type MatchingPoolId = u64;
+pub type MatchedKey = (MatchingPoolId, TipsBalanceKey);
+
+pub const MAX_MATCHING_POOLS_PER_TOKEN: usize = 10;
+pub const MAX_ELIGIBLE_ENTRIES: usize = 10;
+
+/// Sponsor funds that match tips to eligible references. An empty list of servers or reference
+/// types makes every server or reference type eligible. The match ratio is in basis points, so
+/// 10000 matches a tip 1:1.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MatchingPool {
+	id: MatchingPoolId,
+	sponsor: AccountId,
+	ft_identifier: FtIdentifier,
+	server_ids: Vec<ServerId>,
+	reference_types: Vec<ReferenceType>,
+	match_ratio: u16,
+	reference_cap: U128,
+	starts_at: Timestamp,
+	ends_at: Timestamp,
+	amount: U128,
+	matched: U128,
+}
+impl MatchingPool {
+	pub fn new(
+		id: MatchingPoolId,
+		sponsor: &AccountId,
+		ft_identifier: &str,
+		amount: Balance,
+	) -> Self {
+		Self {
+			id,
+			sponsor: sponsor.clone(),
+			ft_identifier: ft_identifier.to_string(),
+			server_ids: Vec::new(),
+			reference_types: Vec::new(),
+			match_ratio: TOTAL_SPLIT_WEIGHT,
+			reference_cap: U128(amount),
+			starts_at: env::block_timestamp(),
+			ends_at: env::block_timestamp(),
+			amount: U128(amount),
+			matched: U128(Zero::zero()),
+		}
+	}
+
+	pub fn get_id(&self) -> MatchingPoolId {
+		self.id
+	}
+
+	pub fn get_sponsor(&self) -> &AccountId {
+		&self.sponsor
+	}
+
+	pub fn get_ft_identifier(&self) -> &str {
+		&self.ft_identifier
+	}
+
+	pub fn get_match_ratio(&self) -> u16 {
+		self.match_ratio
+	}
+
+	pub fn get_reference_cap(&self) -> Balance {
+		self.reference_cap.0
+	}
+
+	pub fn get_ends_at(&self) -> Timestamp {
+		self.ends_at
+	}
+
+	pub fn get_amount(&self) -> Balance {
+		self.amount.0
+	}
+
+	pub fn get_matched(&self) -> Balance {
+		self.matched.0
+	}
+
+	pub fn is_active(&self, now: Timestamp) -> bool {
+		self.starts_at <= now && now < self.ends_at && !self.amount.0.is_zero()
+	}
+
+	pub fn is_eligible(&self, tips_balance_info: &TipsBalanceInfo) -> bool {
+		let server_id = tips_balance_info.get_server_id();
+		let reference_type = tips_balance_info.get_reference_type();
+
+		tips_balance_info.get_ft_identifier() == self.ft_identifier &&
+			(self.server_ids.is_empty() || self.server_ids.contains(server_id)) &&
+			(self.reference_types.is_empty() ||
+				self.reference_types.iter().any(|value| value == reference_type))
+	}
+
+	pub fn set_eligibility(
+		mut self,
+		server_ids: Vec<ServerId>,
+		reference_types: Vec<ReferenceType>,
+	) -> Self {
+		self.server_ids = server_ids;
+		self.reference_types = reference_types;
+		self
+	}
+
+	pub fn set_match_ratio(mut self, match_ratio: u16) -> Self {
+		self.match_ratio = match_ratio;
+		self
+	}
+
+	pub fn set_reference_cap(mut self, reference_cap: Balance) -> Self {
+		self.reference_cap = U128(reference_cap);
+		self
+	}
+
+	pub fn set_window(mut self, starts_at: Timestamp, ends_at: Timestamp) -> Self {
+		self.starts_at = starts_at;
+		self.ends_at = ends_at;
+		self
+	}
+
+	pub fn spend(mut self, amount: Balance) -> Self {
+		self.amount = U128(self.amount.0 - amount);
+		self.matched = U128(self.matched.0 + amount);
+		self
+	}
+
+	pub fn unspend(mut self, amount: Balance) -> Self {
+		self.amount = U128(self.amount.0 + amount);
+		self.matched = U128(self.matched.0 - amount);
+		self
+	}
+}
+
+/// A match credited for a tip. It is kept on the record of the matched tip, so the match can be
+/// rolled back when that tip is cancelled.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, Eq)]
+pub struct TipMatch {
+	pub matching_pool_id: MatchingPoolId,
+	pub sponsor: AccountId,
+	pub tip_id: TipId,
+	pub amount: Balance,
+}
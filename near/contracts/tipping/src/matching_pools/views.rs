@@ -0,0 +1,23 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_matching_pool(&self, matching_pool_id: MatchingPoolId) -> Option<MatchingPool> {
+		self.matching_pools.get(&matching_pool_id)
+	}
+
+	pub fn get_matching_pools(&self, ft_identifier: FtIdentifier) -> Vec<MatchingPool> {
+		self.matching_pool_ids_of(&ft_identifier)
+			.iter()
+			.filter_map(|id| self.matching_pools.get(id))
+			.collect()
+	}
+
+	pub fn get_matched_amount(
+		&self,
+		matching_pool_id: MatchingPoolId,
+		tips_balance_info: TipsBalanceInfo,
+	) -> U128 {
+		U128(self.matched_amount_of(matching_pool_id, &tips_balance_info.key()))
+	}
+}
@@ -0,0 +1,231 @@
+use crate::*;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldTipping {
+	pub owner: AccountId,
+	pub tips_balances: UnorderedMap<TipsBalanceKey, OldTipsBalance>,
+	pub ft_identifiers: UnorderedMap<FtIdentifier, FtIdentifierInfo>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldTipsBalance {
+	pub tips_balance_info: TipsBalanceInfo,
+	pub account_id: Option<AccountId>,
+	pub amount: Balance,
+}
+impl OldTipsBalance {
+	/// Tips made before the migration were not counted, so the lifetime total starts from the
+	/// unclaimed amount and the timestamps stay empty.
+	pub fn migrate(self) -> TipsBalance {
+		let tips_balance = TipsBalance::new(&self.tips_balance_info).set_balance(self.amount);
+		let tips_balance = tips_balance.set_total_amount(self.amount);
+
+		match self.account_id {
+			Some(account_id) => tips_balance.set_account_id(&account_id),
+			None => tips_balance,
+		}
+	}
+}
+
+#[near_bindgen]
+impl Tipping {
+	/// Only the scalar state is rewritten here, the tips balances are converted in place by
+	/// `migrate_tips_balances` so that a large map never has to fit in a single call. The
+	/// contract stays in emergency mode until every balance has been migrated.
+	#[private]
+	#[init(ignore_state)]
+	pub fn migrate() -> Self {
+		let old_state: OldTipping = env::state_read().expect("StateNotExists");
+		let tips_balances_count = old_state.tips_balances.len();
+		let tips_balances = UnorderedMap::try_from_slice(
+			&old_state.tips_balances.try_to_vec().expect("InvalidTipsBalances"),
+		)
+		.expect("InvalidTipsBalances");
+		let tips_balances_migration = if tips_balances_count > 0 { Some(0) } else { None };
+
+		Self {
+			owner: old_state.owner,
+			tips_balances,
+			ft_identifiers: old_state.ft_identifiers,
+			tip_records: LookupMap::new(StorageKeys::TipRecord),
+			tip_record_queues: LookupMap::new(StorageKeys::TipRecordQueue),
+			tip_record_ids: LookupMap::new(StorageKeys::TipRecordId),
+			tip_record_config: TipRecordConfig::default(),
+			supporter_totals: LookupMap::new(StorageKeys::SupporterTotal),
+			top_supporters: LookupMap::new(StorageKeys::TopSupporters),
+			top_references: LookupMap::new(StorageKeys::TopReferences),
+			refund_expiry_days: LookupMap::new(StorageKeys::RefundExpiryDays),
+			contribution_pools: LookupMap::new(StorageKeys::ContributionPool),
+			contributions: LookupMap::new(StorageKeys::Contribution),
+			pause_state: PauseState::default().set_emergency(tips_balances_migration.is_some()),
+			paused_tokens: UnorderedSet::new(StorageKeys::PausedTokens),
+			pausers: UnorderedSet::new(StorageKeys::Pausers),
+			tip_limits: LookupMap::new(StorageKeys::TipLimit),
+			moderators: UnorderedSet::new(StorageKeys::Moderators),
+			blocked_references: UnorderedMap::new(StorageKeys::BlockedReferences),
+			blocked_senders: UnorderedMap::new(StorageKeys::BlockedSenders),
+			rate_limits: LookupMap::new(StorageKeys::RateLimits),
+			rate_limit_exemptions: UnorderedSet::new(StorageKeys::RateLimitExemptions),
+			rate_windows: LookupMap::new(StorageKeys::RateWindows),
+			revenue_splits: LookupMap::new(StorageKeys::RevenueSplits),
+			auto_forward_accounts: UnorderedSet::new(StorageKeys::AutoForwardAccounts),
+			deposits: LookupMap::new(StorageKeys::Deposits),
+			allowances: LookupMap::new(StorageKeys::Allowances),
+			voucher_keys: LookupMap::new(StorageKeys::VoucherKeys),
+			voucher_states: LookupMap::new(StorageKeys::VoucherStates),
+			subscriptions: UnorderedMap::new(StorageKeys::Subscriptions),
+			subscription_ids: LookupMap::new(StorageKeys::SubscriptionIds),
+			subscription_config: SubscriptionConfig::default(),
+			streams: LookupMap::new(StorageKeys::Streams),
+			stream_ids: LookupMap::new(StorageKeys::StreamIds),
+			next_stream_id: 0,
+			conditional_tips: UnorderedMap::new(StorageKeys::ConditionalTips),
+			conditional_tip_ids: LookupMap::new(StorageKeys::ConditionalTipIds),
+			next_conditional_tip_id: 0,
+			campaigns: LookupMap::new(StorageKeys::Campaigns),
+			campaign_contributions: LookupMap::new(StorageKeys::CampaignContributions),
+			matching_pools: LookupMap::new(StorageKeys::MatchingPools),
+			matching_pool_ids: LookupMap::new(StorageKeys::MatchingPoolIds),
+			matched_amounts: LookupMap::new(StorageKeys::MatchedAmounts),
+			next_matching_pool_id: 0,
+			funding_rounds: LookupMap::new(StorageKeys::FundingRounds),
+			funding_round_ids: LookupMap::new(StorageKeys::FundingRoundIds),
+			round_references: LookupMap::new(StorageKeys::RoundReferences),
+			round_contributions: LookupMap::new(StorageKeys::RoundContributions),
+			next_funding_round_id: 0,
+			bounties: LookupMap::new(StorageKeys::Bounties),
+			bounty_ids: LookupMap::new(StorageKeys::BountyIds),
+			next_bounty_id: 0,
+			gift_codes: LookupMap::new(StorageKeys::GiftCodes),
+			gift_code_hashes: LookupMap::new(StorageKeys::GiftCodeHashes),
+			gift_code_commitments: LookupMap::new(StorageKeys::GiftCodeCommitments),
+			revealed_gift_codes: LookupSet::new(StorageKeys::RevealedGiftCodes),
+			reference_keys: LookupMap::new(StorageKeys::ReferenceKeys),
+			key_claims: LookupMap::new(StorageKeys::KeyClaims),
+			tips_balances_migration,
+		}
+	}
+
+	/// Converts up to `limit` tips balances left in the old layout and returns how many are
+	/// still pending.
+	pub fn migrate_tips_balances(&mut self, limit: u64) -> u64 {
+		let from_index = match self.tips_balances_migration {
+			Some(from_index) => from_index,
+			None => return 0,
+		};
+		let old_tips_balances: UnorderedMap<TipsBalanceKey, OldTipsBalance> =
+			UnorderedMap::try_from_slice(
+				&self.tips_balances.try_to_vec().expect("InvalidTipsBalances"),
+			)
+			.expect("InvalidTipsBalances");
+		let keys = old_tips_balances.keys_as_vector();
+		let values = old_tips_balances.values_as_vector();
+		let total_item = keys.len();
+		let to_index = min(from_index.saturating_add(limit), total_item);
+
+		for index in from_index..to_index {
+			let key = keys.get(index).expect("TipsBalanceNotExists");
+			let old_tips_balance = values.get(index).expect("TipsBalanceNotExists");
+
+			// The stored value is still in the old layout, so it is replaced without being read
+			// back as a `TipsBalance`.
+			self.tips_balances.insert_raw(
+				&key.try_to_vec().expect("InvalidTipsBalanceKey"),
+				&old_tips_balance.migrate().try_to_vec().expect("InvalidTipsBalance"),
+			);
+		}
+
+		self.tips_balances_migration = if to_index < total_item { Some(to_index) } else { None };
+
+		log!("Migrated tips balances {} to {}", from_index, to_index);
+
+		total_item - to_index
+	}
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod migration_tests {
+	use super::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	fn write_old_state(count: u64) {
+		let mut ft_identifiers = UnorderedMap::new(StorageKeys::FtIdentifierInfo);
+		ft_identifiers.insert(&"native".to_string(), &FtIdentifierInfo::new("native", 24, "NEAR"));
+
+		let mut tips_balances = UnorderedMap::new(StorageKeys::TipsBalance);
+
+		for index in 0..count {
+			let reference_id = format!("user_id_{}", index);
+			let tips_balance_info =
+				TipsBalanceInfo::new(&accounts(4), "user", &reference_id, "native");
+			let old_tips_balance = OldTipsBalance {
+				tips_balance_info: tips_balance_info.clone(),
+				account_id: None,
+				amount: 10 + index as Balance,
+			};
+
+			tips_balances.insert(&tips_balance_info.key(), &old_tips_balance);
+		}
+
+		env::state_write(&OldTipping { owner: accounts(1), tips_balances, ft_identifiers });
+	}
+
+	#[test]
+	fn migrate_tips_balances_in_pages() {
+		// Initialize contract
+		let context = get_context(accounts(1));
+		testing_env!(context.build());
+		write_old_state(3);
+
+		// Migrate
+		let mut contract = Tipping::migrate();
+
+		assert!(contract.pause_state.is_emergency());
+		assert_eq!(contract.migrate_tips_balances(2), 1);
+		assert_eq!(contract.migrate_tips_balances(2), 0);
+		assert_eq!(contract.migrate_tips_balances(2), 0);
+
+		// Test
+		for index in 0..3 {
+			let reference_id = format!("user_id_{}", index);
+			let tips_balance_info =
+				TipsBalanceInfo::new(&accounts(4), "user", &reference_id, "native");
+			let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap().tips_balance;
+
+			assert_eq!(tips_balance.get_amount(), 10 + index as Balance);
+			assert_eq!(tips_balance.get_total_amount(), 10 + index as Balance);
+		}
+
+		contract.exit_emergency_mode();
+		assert!(!contract.pause_state.is_emergency());
+	}
+
+	#[test]
+	#[should_panic(expected = "MigrationPending")]
+	fn cant_exit_emergency_mode_before_migration() {
+		// Initialize contract
+		let context = get_context(accounts(1));
+		testing_env!(context.build());
+		write_old_state(3);
+
+		// Migrate
+		let mut contract = Tipping::migrate();
+		contract.migrate_tips_balances(2);
+
+		// Test
+		contract.exit_emergency_mode();
+	}
+}
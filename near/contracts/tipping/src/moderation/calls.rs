@@ -0,0 +1,97 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn add_moderator(&mut self, account_id: AccountId) {
+		self.assert_owner();
+		self.moderators.insert(&account_id);
+	}
+
+	pub fn remove_moderator(&mut self, account_id: AccountId) {
+		self.assert_owner();
+		self.moderators.remove(&account_id);
+	}
+
+	/// Rejects new tips to a reference and freezes its balances until it is unblocked.
+	pub fn block_reference(
+		&mut self,
+		server_id: ServerId,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+		reason: String,
+	) {
+		self.assert_moderator();
+		self.assert_block_reason(&reason);
+
+		let block_info = BlockInfo::new(&reason, &env::predecessor_account_id());
+		let reference_key = (server_id, reference_type, reference_id);
+
+		self.blocked_references.insert(&reference_key, &block_info);
+
+		log!("{}/{} on {} blocked: {}", reference_key.1, reference_key.2, reference_key.0, reason);
+	}
+
+	pub fn unblock_reference(
+		&mut self,
+		server_id: ServerId,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+	) {
+		self.assert_moderator();
+
+		let reference_key = (server_id, reference_type, reference_id);
+
+		self.blocked_references.remove(&reference_key).expect("ReferenceNotBlocked");
+
+		log!("{}/{} on {} unblocked", reference_key.1, reference_key.2, reference_key.0);
+	}
+
+	pub fn block_sender(&mut self, account_id: AccountId, reason: String) {
+		self.assert_moderator();
+		self.assert_block_reason(&reason);
+
+		let block_info = BlockInfo::new(&reason, &env::predecessor_account_id());
+
+		self.blocked_senders.insert(&account_id, &block_info);
+
+		log!("{} blocked: {}", account_id, reason);
+	}
+
+	pub fn unblock_sender(&mut self, account_id: AccountId) {
+		self.assert_moderator();
+		self.blocked_senders.remove(&account_id).expect("SenderNotBlocked");
+
+		log!("{} unblocked", account_id);
+	}
+
+	/// Pays the frozen balance of a blocked reference out to an account chosen by the owner, for
+	/// example the rights holder of a DMCA claim.
+	#[payable]
+	pub fn redirect_blocked_tips(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		receiver: AccountId,
+	) {
+		assert_one_yocto();
+		self.assert_owner();
+		assert!(self.is_reference_blocked(&tips_balance_info), "ReferenceNotBlocked");
+
+		let key = tips_balance_info.key();
+		let tips_balance = self.tips_balances.get(&key).expect("TipsBalanceNotExists");
+		let amount = tips_balance.get_amount();
+
+		assert!(amount > 0, "NothingToRedirect");
+
+		let ft_id = tips_balance_info.get_ft_identifier().to_string();
+
+		self.tips_balances
+			.insert(&key, &tips_balance.set_balance(Zero::zero()).set_claimed());
+		self.transfer(&receiver, &ft_id, amount).then(
+			Self::ext(env::current_account_id()).resolve_redirect_blocked_tips(
+				tips_balance_info,
+				receiver,
+				amount,
+			),
+		);
+	}
+}
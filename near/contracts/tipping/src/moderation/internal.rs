@@ -0,0 +1,81 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn assert_moderator(&self) {
+		let caller = env::predecessor_account_id();
+
+		assert!(caller == self.owner || self.moderators.contains(&caller), "UnauthorizedModerator");
+	}
+
+	pub(crate) fn reference_key(&self, tips_balance_info: &TipsBalanceInfo) -> ReferenceKey {
+		(
+			tips_balance_info.get_server_id().clone(),
+			tips_balance_info.get_reference_type().to_string(),
+			tips_balance_info.get_reference_id().to_string(),
+		)
+	}
+
+	pub(crate) fn is_reference_blocked(&self, tips_balance_info: &TipsBalanceInfo) -> bool {
+		self.blocked_references.get(&self.reference_key(tips_balance_info)).is_some()
+	}
+
+	/// Returns the reason a tip from the sender to the reference is rejected, if it is.
+	pub(crate) fn blocked_reason(
+		&self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+	) -> Option<&'static str> {
+		if self.blocked_senders.get(sender).is_some() {
+			return Some("SenderBlocked")
+		}
+
+		if self.is_reference_blocked(tips_balance_info) {
+			return Some("ReferenceBlocked")
+		}
+
+		None
+	}
+
+	pub(crate) fn assert_not_blocked(
+		&self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+	) {
+		if let Some(reason) = self.blocked_reason(sender, tips_balance_info) {
+			panic!("{}", reason)
+		}
+	}
+
+	pub(crate) fn assert_reference_not_blocked(&self, tips_balance_info: &TipsBalanceInfo) {
+		assert!(!self.is_reference_blocked(tips_balance_info), "ReferenceBlocked");
+	}
+
+	pub(crate) fn assert_block_reason(&self, reason: &str) {
+		assert!(reason.len() <= MAX_BLOCK_REASON_LENGTH, "ReasonTooLong");
+	}
+
+	pub(crate) fn internal_resolve_redirect_blocked_tips(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		receiver: AccountId,
+		amount: Balance,
+	) {
+		require!(env::promise_results_count() == 1);
+
+		let key = tips_balance_info.key();
+
+		match env::promise_result(0) {
+			PromiseResult::Successful(_) => {
+				let ft_identifier = tips_balance_info.get_ft_identifier();
+				let (symbol, formatted) = self.formatted_amount_of(ft_identifier, amount);
+				let reference = tips_balance_info.reference();
+
+				log!("{} {} from {} redirected to {}", formatted, symbol, reference, receiver);
+			},
+			_ =>
+				if let Some(tips_balance) = self.tips_balances.get(&key) {
+					self.tips_balances.insert(&key, &tips_balance.add_balance(amount));
+				},
+		}
+	}
+}
@@ -0,0 +1,14 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	#[private]
+	pub fn resolve_redirect_blocked_tips(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		receiver: AccountId,
+		amount: Balance,
+	) {
+		self.internal_resolve_redirect_blocked_tips(tips_balance_info, receiver, amount);
+	}
+}
@@ -0,0 +1,104 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod moderation_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	#[should_panic(expected = "ReferenceBlocked")]
+	fn cant_send_tip_to_blocked_reference() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.add_moderator(accounts(2));
+
+		// Moderator blocks user-user_id
+		testing_env!(context.signer_account_id(accounts(2)).build());
+		contract.block_reference(
+			accounts(4),
+			String::from("user"),
+			String::from("user_id"),
+			String::from("DMCA"),
+		);
+
+		// Send tip to user-user_id
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(10)
+			.build());
+		contract.send_tip(tips_balance_info, None);
+	}
+
+	#[test]
+	#[should_panic(expected = "SenderBlocked")]
+	fn cant_send_tip_from_blocked_sender() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.block_sender(accounts(3), String::from("Sanctioned"));
+
+		// Send tip to user-user_id
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(10)
+			.build());
+		contract.send_tip(tips_balance_info, None);
+	}
+
+	#[test]
+	#[should_panic(expected = "ReferenceBlocked")]
+	fn cant_claim_tip_of_blocked_reference() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to user-user_id
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Owner blocks user-user_id
+		testing_env!(context.predecessor_account_id(accounts(1)).build());
+		contract.block_reference(
+			accounts(4),
+			String::from("user"),
+			String::from("user_id"),
+			String::from("Abuse"),
+		);
+
+		let blocked = contract.get_blocked_references(None, None);
+
+		assert_eq!(blocked.data.len(), 1);
+		assert_eq!(blocked.data[0].block_info.get_reason(), "Abuse");
+
+		// Claim tip
+		testing_env!(context
+			.signer_account_id(accounts(5))
+			.predecessor_account_id(accounts(5))
+			.attached_deposit(1)
+			.build());
+		contract.claim_tip(tips_balance_info);
+	}
+}
@@ -0,0 +1,68 @@
+use crate::*;
+
+pub const MAX_BLOCK_REASON_LENGTH: usize = 256;
+
+pub type ReferenceKey = (ServerId, ReferenceType, ReferenceId);
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BlockInfo {
+	reason: String,
+	blocked_by: AccountId,
+	blocked_at: Timestamp,
+}
+impl BlockInfo {
+	pub fn new(reason: &str, blocked_by: &AccountId) -> Self {
+		Self {
+			reason: reason.to_string(),
+			blocked_by: blocked_by.clone(),
+			blocked_at: env::block_timestamp(),
+		}
+	}
+
+	pub fn get_reason(&self) -> &str {
+		&self.reason
+	}
+
+	pub fn get_blocked_by(&self) -> &AccountId {
+		&self.blocked_by
+	}
+
+	pub fn get_blocked_at(&self) -> Timestamp {
+		self.blocked_at
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BlockedReference {
+	pub server_id: ServerId,
+	pub reference_type: ReferenceType,
+	pub reference_id: ReferenceId,
+	pub block_info: BlockInfo,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BlockedSender {
+	pub account_id: AccountId,
+	pub block_info: BlockInfo,
+}
+
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BlockedReferenceWithPagination {
+	pub data: Vec<BlockedReference>,
+	pub meta: Metadata,
+}
+
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BlockedSenderWithPagination {
+	pub data: Vec<BlockedSender>,
+	pub meta: Metadata,
+}
@@ -0,0 +1,87 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_blocked_reference(
+		&self,
+		server_id: ServerId,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+	) -> Option<BlockInfo> {
+		self.blocked_references.get(&(server_id, reference_type, reference_id))
+	}
+
+	pub fn get_blocked_sender(&self, account_id: AccountId) -> Option<BlockInfo> {
+		self.blocked_senders.get(&account_id)
+	}
+
+	pub fn get_blocked_references(
+		&self,
+		page_number: Option<u64>,
+		page_limit: Option<u64>,
+	) -> BlockedReferenceWithPagination {
+		if self.blocked_references.is_empty() {
+			return BlockedReferenceWithPagination::default()
+		}
+
+		let total_item = self.blocked_references.len();
+		let meta = self.metadata(total_item, page_number, page_limit);
+
+		if meta.current_page.is_none() {
+			return BlockedReferenceWithPagination::default()
+		}
+
+		let page_number = meta.current_page.unwrap();
+		let page_limit = meta.items_per_page;
+		let from_index: u64 = (page_number - 1) * page_limit;
+		let keys = self.blocked_references.keys_as_vector();
+		let values = self.blocked_references.values_as_vector();
+		let data = (from_index..min(from_index + page_limit, total_item))
+			.filter_map(|index| {
+				let (server_id, reference_type, reference_id) = keys.get(index)?;
+				let block_info = values.get(index)?;
+
+				Some(BlockedReference { server_id, reference_type, reference_id, block_info })
+			})
+			.collect();
+
+		BlockedReferenceWithPagination { data, meta }
+	}
+
+	pub fn get_blocked_senders(
+		&self,
+		page_number: Option<u64>,
+		page_limit: Option<u64>,
+	) -> BlockedSenderWithPagination {
+		if self.blocked_senders.is_empty() {
+			return BlockedSenderWithPagination::default()
+		}
+
+		let total_item = self.blocked_senders.len();
+		let meta = self.metadata(total_item, page_number, page_limit);
+
+		if meta.current_page.is_none() {
+			return BlockedSenderWithPagination::default()
+		}
+
+		let page_number = meta.current_page.unwrap();
+		let page_limit = meta.items_per_page;
+		let from_index: u64 = (page_number - 1) * page_limit;
+		let keys = self.blocked_senders.keys_as_vector();
+		let values = self.blocked_senders.values_as_vector();
+		let data = (from_index..min(from_index + page_limit, total_item))
+			.filter_map(|index| {
+				let account_id = keys.get(index)?;
+				let block_info = values.get(index)?;
+
+				Some(BlockedSender { account_id, block_info })
+			})
+			.collect();
+
+		BlockedSenderWithPagination { data, meta }
+	}
+
+	pub fn get_moderators(&self) -> Vec<AccountId> {
+		self.moderators.to_vec()
+	}
+}
@@ -0,0 +1,59 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn add_pauser(&mut self, account_id: AccountId) {
+		self.assert_owner();
+		self.pausers.insert(&account_id);
+	}
+
+	pub fn remove_pauser(&mut self, account_id: AccountId) {
+		self.assert_owner();
+		self.pausers.remove(&account_id);
+	}
+
+	pub fn pause(&mut self, feature: PauseFeature) {
+		self.assert_pauser();
+		self.pause_state = self.pause_state.clone().set_paused(feature, true);
+
+		log!("{:?} paused", feature);
+	}
+
+	pub fn unpause(&mut self, feature: PauseFeature) {
+		self.assert_pauser();
+		self.pause_state = self.pause_state.clone().set_paused(feature, false);
+
+		log!("{:?} unpaused", feature);
+	}
+
+	pub fn pause_token(&mut self, ft_identifier: FtIdentifier) {
+		self.assert_pauser();
+		self.paused_tokens.insert(&ft_identifier);
+
+		log!("{} paused", ft_identifier);
+	}
+
+	pub fn unpause_token(&mut self, ft_identifier: FtIdentifier) {
+		self.assert_pauser();
+		self.paused_tokens.remove(&ft_identifier);
+
+		log!("{} unpaused", ft_identifier);
+	}
+
+	/// Pauses tipping, claiming and referencing for every token at once. Any pauser can enter the
+	/// emergency mode, only the owner can leave it.
+	pub fn enter_emergency_mode(&mut self) {
+		self.assert_pauser();
+		self.pause_state = self.pause_state.clone().set_emergency(true);
+
+		log!("Emergency mode entered");
+	}
+
+	pub fn exit_emergency_mode(&mut self) {
+		self.assert_owner();
+		assert!(self.tips_balances_migration.is_none(), "MigrationPending");
+		self.pause_state = self.pause_state.clone().set_emergency(false);
+
+		log!("Emergency mode exited");
+	}
+}
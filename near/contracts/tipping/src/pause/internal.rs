@@ -0,0 +1,48 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn assert_pauser(&self) {
+		let caller = env::predecessor_account_id();
+
+		assert!(caller == self.owner || self.pausers.contains(&caller), "UnauthorizedPauser");
+	}
+
+	/// Returns the reason a feature is paused for a token, if it is.
+	pub(crate) fn paused_reason(
+		&self,
+		feature: PauseFeature,
+		ft_identifier: Option<&str>,
+	) -> Option<&'static str> {
+		if self.pause_state.is_emergency() {
+			return Some("ContractPaused")
+		}
+
+		if self.pause_state.is_paused(feature) {
+			let reason = match feature {
+				PauseFeature::Tipping => "TippingPaused",
+				PauseFeature::Claiming => "ClaimingPaused",
+				PauseFeature::Referencing => "ReferencingPaused",
+			};
+
+			return Some(reason)
+		}
+
+		if let Some(ft_identifier) = ft_identifier {
+			if self.paused_tokens.contains(&ft_identifier.to_string()) {
+				return Some("TokenPaused")
+			}
+		}
+
+		None
+	}
+
+	pub(crate) fn is_paused(&self, feature: PauseFeature, ft_identifier: Option<&str>) -> bool {
+		self.paused_reason(feature, ft_identifier).is_some()
+	}
+
+	pub(crate) fn assert_not_paused(&self, feature: PauseFeature, ft_identifier: Option<&str>) {
+		if let Some(reason) = self.paused_reason(feature, ft_identifier) {
+			panic!("{}", reason)
+		}
+	}
+}
@@ -0,0 +1,118 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod pause_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	#[should_panic(expected = "TippingPaused")]
+	fn cant_send_tip_when_tipping_paused() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Pauser pauses tipping
+		contract.add_pauser(accounts(2));
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.build());
+		contract.pause(PauseFeature::Tipping);
+
+		// Send tip to user-user_id
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(10)
+			.build());
+		contract.send_tip(tips_balance_info, None);
+	}
+
+	#[test]
+	#[should_panic(expected = "TokenPaused")]
+	fn cant_send_tip_when_token_paused() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.pause_token(String::from("native"));
+
+		// Send tip to user-user_id
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(10)
+			.build());
+		contract.send_tip(tips_balance_info, None);
+	}
+
+	#[test]
+	fn emergency_mode_is_visible_and_lifted_by_owner() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.add_pauser(accounts(2));
+
+		// Pauser enters the emergency mode
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.build());
+		contract.enter_emergency_mode();
+		assert!(contract.get_pause_status().pause_state.is_emergency());
+
+		// Owner exits the emergency mode
+		testing_env!(context
+			.signer_account_id(accounts(1))
+			.predecessor_account_id(accounts(1))
+			.build());
+		contract.exit_emergency_mode();
+		assert!(!contract.get_pause_status().pause_state.is_emergency());
+	}
+
+	#[test]
+	#[should_panic(expected = "UnauthorizedAdmin")]
+	fn cant_exit_emergency_mode_as_pauser() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.add_pauser(accounts(2));
+
+		// Pauser enters and tries to exit the emergency mode
+		testing_env!(context.predecessor_account_id(accounts(2)).build());
+		contract.enter_emergency_mode();
+		contract.exit_emergency_mode();
+	}
+
+	#[test]
+	#[should_panic(expected = "UnauthorizedPauser")]
+	fn cant_pause_through_other_contract() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Owner signs a call that reaches the contract through accounts(2)
+		testing_env!(context.predecessor_account_id(accounts(2)).build());
+		contract.pause(PauseFeature::Tipping);
+	}
+}
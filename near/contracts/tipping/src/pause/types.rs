@@ -0,0 +1,59 @@
+use crate::*;
+
+/// Entry points that can be paused. `Tipping` covers every way of paying into the contract or
+/// crediting a reference, `Claiming` every way of paying out of it or back into a deposit (claims,
+/// refunds and cancellations) and `Referencing` the reference binding calls.
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PauseFeature {
+	Tipping,
+	Claiming,
+	Referencing,
+}
+
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PauseState {
+	emergency: bool,
+	tipping: bool,
+	claiming: bool,
+	referencing: bool,
+}
+impl PauseState {
+	pub fn is_emergency(&self) -> bool {
+		self.emergency
+	}
+
+	pub fn is_paused(&self, feature: PauseFeature) -> bool {
+		match feature {
+			PauseFeature::Tipping => self.tipping,
+			PauseFeature::Claiming => self.claiming,
+			PauseFeature::Referencing => self.referencing,
+		}
+	}
+
+	pub fn set_emergency(mut self, emergency: bool) -> Self {
+		self.emergency = emergency;
+		self
+	}
+
+	pub fn set_paused(mut self, feature: PauseFeature, paused: bool) -> Self {
+		match feature {
+			PauseFeature::Tipping => self.tipping = paused,
+			PauseFeature::Claiming => self.claiming = paused,
+			PauseFeature::Referencing => self.referencing = paused,
+		}
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PauseStatus {
+	pub pause_state: PauseState,
+	pub paused_tokens: Vec<FtIdentifier>,
+}
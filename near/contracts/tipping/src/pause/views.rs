@@ -0,0 +1,15 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_pause_status(&self) -> PauseStatus {
+		PauseStatus {
+			pause_state: self.pause_state.clone(),
+			paused_tokens: self.paused_tokens.to_vec(),
+		}
+	}
+
+	pub fn get_pausers(&self) -> Vec<AccountId> {
+		self.pausers.to_vec()
+	}
+}
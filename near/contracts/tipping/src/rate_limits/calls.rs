@@ -0,0 +1,39 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Limits how many tips, and how much volume, a single sender may send to references of a
+	/// server in a token within a sliding window. Both limits `None` removes the rate limit.
+	pub fn set_rate_limit(
+		&mut self,
+		server_id: ServerId,
+		ft_identifier: FtIdentifier,
+		window_seconds: u64,
+		max_tips: Option<u64>,
+		max_volume: Option<U128>,
+	) {
+		self.assert_owner();
+
+		let limit_key = (server_id, ft_identifier);
+
+		if max_tips.is_none() && max_volume.is_none() {
+			self.rate_limits.remove(&limit_key);
+			return
+		}
+
+		assert!(window_seconds > 0, "InvalidRateLimitWindow");
+
+		self.rate_limits
+			.insert(&limit_key, &RateLimit::new(window_seconds, max_tips, max_volume));
+	}
+
+	pub fn add_rate_limit_exemption(&mut self, account_id: AccountId) {
+		self.assert_owner();
+		self.rate_limit_exemptions.insert(&account_id);
+	}
+
+	pub fn remove_rate_limit_exemption(&mut self, account_id: AccountId) {
+		self.assert_owner();
+		self.rate_limit_exemptions.remove(&account_id);
+	}
+}
@@ -0,0 +1,111 @@
+use crate::*;
+
+impl Tipping {
+	fn rate_window(&self, window_key: &RateWindowKey, window: u64) -> RateWindow {
+		let rate_window = self.rate_windows.get(window_key).unwrap_or_default();
+
+		rate_window.roll(window, env::block_timestamp())
+	}
+
+	fn rate_keys(
+		&self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+	) -> (RateLimitKey, RateWindowKey) {
+		let server_id = tips_balance_info.get_server_id().clone();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+
+		((server_id.clone(), ft_identifier.clone()), (sender.clone(), server_id, ft_identifier))
+	}
+
+	/// Returns the reason a tip would go over the sender's rate limit, if it would.
+	pub(crate) fn rate_limit_reason(
+		&self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+	) -> Option<&'static str> {
+		if self.rate_limit_exemptions.contains(sender) {
+			return None
+		}
+
+		let (limit_key, window_key) = self.rate_keys(sender, tips_balance_info);
+		let rate_limit = self.rate_limits.get(&limit_key)?;
+		let window = rate_limit.get_window();
+		let (tips, volume) =
+			self.rate_window(&window_key, window).usage(window, env::block_timestamp());
+
+		if matches!(rate_limit.get_max_tips(), Some(max_tips) if tips + 1 > max_tips) {
+			return Some("RateLimitExceeded")
+		}
+
+		if matches!(rate_limit.get_max_volume(), Some(max_volume) if volume + amount > max_volume) {
+			return Some("RateLimitExceeded")
+		}
+
+		None
+	}
+
+	pub(crate) fn record_rate_limit(
+		&mut self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+	) {
+		if self.rate_limit_exemptions.contains(sender) {
+			return
+		}
+
+		let (limit_key, window_key) = self.rate_keys(sender, tips_balance_info);
+
+		if let Some(rate_limit) = self.rate_limits.get(&limit_key) {
+			let rate_window = self.rate_window(&window_key, rate_limit.get_window()).add(amount);
+
+			self.rate_windows.insert(&window_key, &rate_window);
+		}
+	}
+
+	/// Gives back the rate usage of a cancelled tip.
+	pub(crate) fn release_rate_limit(
+		&mut self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+		tipped_at: Timestamp,
+	) {
+		if self.rate_limit_exemptions.contains(sender) {
+			return
+		}
+
+		let (limit_key, window_key) = self.rate_keys(sender, tips_balance_info);
+
+		if let (Some(rate_limit), Some(_)) =
+			(self.rate_limits.get(&limit_key), self.rate_windows.get(&window_key))
+		{
+			let rate_window =
+				self.rate_window(&window_key, rate_limit.get_window()).remove(amount, tipped_at);
+
+			self.rate_windows.insert(&window_key, &rate_window);
+		}
+	}
+
+	pub(crate) fn rate_usage(
+		&self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+	) -> RateUsage {
+		let exempted = self.rate_limit_exemptions.contains(sender);
+		let (limit_key, window_key) = self.rate_keys(sender, tips_balance_info);
+		let rate_limit = self.rate_limits.get(&limit_key);
+		let (tips, volume) = match &rate_limit {
+			Some(rate_limit) => {
+				let window = rate_limit.get_window();
+
+				self.rate_window(&window_key, window).usage(window, env::block_timestamp())
+			},
+			None => (Zero::zero(), Zero::zero()),
+		};
+
+		RateUsage { rate_limit, tips, volume: U128(volume), exempted }
+	}
+}
@@ -0,0 +1,118 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod rate_limits_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	#[should_panic(expected = "RateLimitExceeded")]
+	fn cant_send_tip_over_rate_limit() {
+		// Initialize contract, two tips per minute
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.set_rate_limit(accounts(4), String::from("native"), 60, Some(2), None);
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send three tips to user-user_id
+		for _ in 0..3 {
+			testing_env!(context
+				.signer_account_id(accounts(3))
+				.predecessor_account_id(accounts(3))
+				.attached_deposit(10)
+				.build());
+			contract.send_tip(tips_balance_info.clone(), None);
+		}
+	}
+
+	#[test]
+	fn rate_limit_slides_with_time() {
+		// Initialize contract, two tips per minute
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.set_rate_limit(accounts(4), String::from("native"), 60, Some(2), None);
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send two tips, then one more once the window has passed
+		for timestamp in [0, 0, 120 * NANOSECONDS_PER_SECOND] {
+			testing_env!(context
+				.signer_account_id(accounts(3))
+				.predecessor_account_id(accounts(3))
+				.block_timestamp(timestamp)
+				.attached_deposit(10)
+				.build());
+			contract.send_tip(tips_balance_info.clone(), None);
+		}
+
+		// Test
+		let rate_usage = contract.get_rate_usage(accounts(3), tips_balance_info);
+
+		assert_eq!(rate_usage.tips, 1);
+		assert_eq!(rate_usage.volume, U128(10));
+	}
+
+	#[test]
+	fn bounty_award_is_not_rate_limited() {
+		// Initialize contract, two tips per minute
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.set_rate_limit(accounts(4), String::from("native"), 60, Some(2), None);
+
+		// Payload
+		let question_1 = TipsBalanceInfo::new(&accounts(4), "post", "question_1", "native");
+		let answer_1 = TipsBalanceInfo::new(&accounts(4), "comment", "answer_1", "native");
+
+		// accounts(1) posts a bounty on question_1 and awards it in three parts
+		testing_env!(context.attached_deposit(30).build());
+		let id = contract.create_bounty(question_1, U128(30), 1000);
+
+		testing_env!(context.attached_deposit(0).build());
+		contract.award_bounty(id, vec![(answer_1.clone(), U128(10)); 3], None);
+
+		// Test
+		let rate_usage = contract.get_rate_usage(accounts(1), answer_1);
+
+		assert_eq!(rate_usage.tips, 0);
+		assert_eq!(rate_usage.volume, U128(0));
+	}
+
+	#[test]
+	fn exempted_sender_is_not_rate_limited() {
+		// Initialize contract, two tips per minute
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.set_rate_limit(accounts(4), String::from("native"), 60, Some(2), None);
+		contract.add_rate_limit_exemption(accounts(3));
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send three tips to user-user_id
+		for _ in 0..3 {
+			testing_env!(context
+				.signer_account_id(accounts(3))
+				.predecessor_account_id(accounts(3))
+				.attached_deposit(10)
+				.build());
+			contract.send_tip(tips_balance_info.clone(), None);
+		}
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_tip_count(), 3);
+	}
+}
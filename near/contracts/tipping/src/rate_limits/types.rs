@@ -0,0 +1,101 @@
+use crate::*;
+
+pub type RateLimitKey = (ServerId, FtIdentifier);
+pub type RateWindowKey = (AccountId, ServerId, FtIdentifier);
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RateLimit {
+	window: u64,
+	max_tips: Option<u64>,
+	max_volume: Option<U128>,
+}
+impl RateLimit {
+	pub fn new(window_seconds: u64, max_tips: Option<u64>, max_volume: Option<U128>) -> Self {
+		Self { window: window_seconds.saturating_mul(NANOSECONDS_PER_SECOND), max_tips, max_volume }
+	}
+
+	pub fn get_window(&self) -> u64 {
+		self.window
+	}
+
+	pub fn get_max_tips(&self) -> Option<u64> {
+		self.max_tips
+	}
+
+	pub fn get_max_volume(&self) -> Option<Balance> {
+		self.max_volume.map(|volume| volume.0)
+	}
+}
+
+/// Sliding window counter of a sender's tips. Only the current and the previous fixed window are
+/// kept, the previous one is weighted by how much of it still overlaps the sliding window.
+#[derive(BorshDeserialize, BorshSerialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct RateWindow {
+	started_at: Timestamp,
+	tips: u64,
+	volume: Balance,
+	previous_tips: u64,
+	previous_volume: Balance,
+}
+impl RateWindow {
+	pub fn roll(mut self, window: u64, now: Timestamp) -> Self {
+		let elapsed = now.saturating_sub(self.started_at);
+
+		if elapsed >= window.saturating_mul(2) {
+			self = Self { started_at: now, ..Self::default() };
+		} else if elapsed >= window {
+			self.previous_tips = self.tips;
+			self.previous_volume = self.volume;
+			self.tips = Zero::zero();
+			self.volume = Zero::zero();
+			self.started_at += window;
+		}
+
+		self
+	}
+
+	/// Estimated tips and volume in the sliding window ending now. Expects a rolled window.
+	pub fn usage(&self, window: u64, now: Timestamp) -> (u64, Balance) {
+		if window == 0 {
+			return (self.tips, self.volume)
+		}
+
+		let elapsed = now.saturating_sub(self.started_at).min(window);
+		let overlap = (window - elapsed) as u128;
+		let previous_tips = (self.previous_tips as u128 * overlap / window as u128) as u64;
+		let previous_volume =
+			(U256::from(self.previous_volume) * U256::from(overlap) / U256::from(window)).as_u128();
+
+		(self.tips + previous_tips, self.volume + previous_volume)
+	}
+
+	pub fn add(mut self, amount: Balance) -> Self {
+		self.tips += 1;
+		self.volume += amount;
+		self
+	}
+
+	/// Takes back a tip counted at `tipped_at`, from whichever fixed window it fell in. Expects a
+	/// rolled window.
+	pub fn remove(mut self, amount: Balance, tipped_at: Timestamp) -> Self {
+		if tipped_at >= self.started_at {
+			self.tips = self.tips.saturating_sub(1);
+			self.volume = self.volume.saturating_sub(amount);
+		} else {
+			self.previous_tips = self.previous_tips.saturating_sub(1);
+			self.previous_volume = self.previous_volume.saturating_sub(amount);
+		}
+
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RateUsage {
+	pub rate_limit: Option<RateLimit>,
+	pub tips: u64,
+	pub volume: U128,
+	pub exempted: bool,
+}
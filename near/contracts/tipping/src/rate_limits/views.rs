@@ -0,0 +1,24 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_rate_limit(
+		&self,
+		server_id: ServerId,
+		ft_identifier: FtIdentifier,
+	) -> Option<RateLimit> {
+		self.rate_limits.get(&(server_id, ft_identifier))
+	}
+
+	pub fn get_rate_usage(
+		&self,
+		sender: AccountId,
+		tips_balance_info: TipsBalanceInfo,
+	) -> RateUsage {
+		self.rate_usage(&sender, &tips_balance_info)
+	}
+
+	pub fn get_rate_limit_exemptions(&self) -> Vec<AccountId> {
+		self.rate_limit_exemptions.to_vec()
+	}
+}
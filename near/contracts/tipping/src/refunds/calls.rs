@@ -0,0 +1,59 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Sets the number of days after their first tip when senders may take back tips to unclaimed
+	/// references of the calling server. `None` disables refunds.
+	pub fn set_refund_expiry(&mut self, expiry_days: Option<u64>) {
+		let server_id = env::signer_account_id();
+
+		match expiry_days {
+			Some(expiry_days) => {
+				assert!(expiry_days > 0, "InvalidExpiryDays");
+				self.refund_expiry_days.insert(&server_id, &expiry_days);
+			},
+			None => {
+				self.refund_expiry_days.remove(&server_id);
+			},
+		}
+	}
+
+	#[payable]
+	pub fn refund_expired_tips(&mut self, tips_balance_info: TipsBalanceInfo) {
+		assert_one_yocto();
+		self.assert_not_paused(PauseFeature::Claiming, Some(tips_balance_info.get_ft_identifier()));
+
+		let sender = env::predecessor_account_id();
+
+		self.assert_not_blocked(&sender, &tips_balance_info);
+
+		let key = tips_balance_info.key();
+		let tips_balance = self.tips_balances.get(&key).expect("TipsBalanceNotExists");
+
+		assert!(tips_balance.get_account_id().is_none(), "ReferenceAlreadyClaimed");
+
+		let amount = self.refundable_amount(&tips_balance, &sender);
+
+		assert!(amount > 0, "NothingToRefund");
+
+		let expires_at = self.tips_expires_at(&tips_balance, &sender).expect("RefundNotAvailable");
+
+		assert!(env::block_timestamp() >= expires_at, "TipsNotExpired");
+
+		let contribution = self.current_contribution(&key, &sender).expect("NothingToRefund");
+		let ft_id = tips_balance_info.get_ft_identifier().to_string();
+		let remaining = tips_balance.get_amount() - amount;
+
+		self.tips_balances.insert(&key, &tips_balance.set_balance(remaining));
+		self.remove_contribution(&sender, &key, contribution.get_amount());
+
+		self.transfer(&sender, &ft_id, amount).then(
+			Self::ext(env::current_account_id()).resolve_refund_expired_tips(
+				sender,
+				tips_balance_info,
+				amount,
+				contribution,
+			),
+		);
+	}
+}
@@ -0,0 +1,169 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn add_contribution(
+		&mut self,
+		sender: &AccountId,
+		key: &TipsBalanceKey,
+		amount: Balance,
+	) {
+		let contribution_pool = self.contribution_pools.get(key).unwrap_or_default();
+		let contribution_key = (key.clone(), sender.clone());
+		let contribution = match self.contributions.get(&contribution_key) {
+			Some(contribution) if contribution.get_round() == contribution_pool.get_round() =>
+				contribution,
+			_ => Contribution::new(contribution_pool.get_round()),
+		};
+
+		self.contributions.insert(&contribution_key, &contribution.add(amount));
+		self.contribution_pools.insert(key, &contribution_pool.add(amount));
+	}
+
+	/// Puts back a contribution taken by a failed refund, keeping the time the sender first
+	/// contributed so the refund stays available.
+	pub(crate) fn restore_contribution(
+		&mut self,
+		sender: &AccountId,
+		key: &TipsBalanceKey,
+		contribution: &Contribution,
+	) {
+		self.add_contribution(sender, key, contribution.get_amount());
+
+		let contribution_key = (key.clone(), sender.clone());
+
+		if let Some(restored) = self.contributions.get(&contribution_key) {
+			let contributed_at =
+				min(restored.get_contributed_at(), contribution.get_contributed_at());
+
+			self.contributions
+				.insert(&contribution_key, &restored.set_contributed_at(contributed_at));
+		}
+	}
+
+	pub(crate) fn remove_contribution(
+		&mut self,
+		sender: &AccountId,
+		key: &TipsBalanceKey,
+		amount: Balance,
+	) {
+		let contribution_pool = self.contribution_pools.get(key).unwrap_or_default();
+		let contribution_key = (key.clone(), sender.clone());
+
+		if let Some(contribution) = self.contributions.get(&contribution_key) {
+			if contribution.get_round() != contribution_pool.get_round() {
+				return
+			}
+
+			let contribution = contribution.remove(amount);
+
+			if contribution.get_amount().is_zero() {
+				self.contributions.remove(&contribution_key);
+			} else {
+				self.contributions.insert(&contribution_key, &contribution);
+			}
+
+			self.contribution_pools.insert(key, &contribution_pool.remove(amount));
+		}
+	}
+
+	/// Drops every contribution to a balance once it is claimed, so later refunds only cover tips
+	/// sent after the claim.
+	pub(crate) fn reset_contributions(&mut self, key: &TipsBalanceKey) {
+		if let Some(contribution_pool) = self.contribution_pools.get(key) {
+			self.contribution_pools.insert(key, &contribution_pool.next_round());
+		}
+	}
+
+	pub(crate) fn current_contribution(
+		&self,
+		key: &TipsBalanceKey,
+		sender: &AccountId,
+	) -> Option<Contribution> {
+		let contribution_pool = self.contribution_pools.get(key).unwrap_or_default();
+		let contribution_key = (key.clone(), sender.clone());
+
+		self.contributions
+			.get(&contribution_key)
+			.filter(|contribution| contribution.get_round() == contribution_pool.get_round())
+	}
+
+	pub(crate) fn contribution_of(&self, key: &TipsBalanceKey, sender: &AccountId) -> Balance {
+		match self.current_contribution(key, sender) {
+			Some(contribution) => contribution.get_amount(),
+			None => Zero::zero(),
+		}
+	}
+
+	/// Each sender's tips expire counting from their own first contribution, so tips from other
+	/// senders can't push the refund back.
+	pub(crate) fn tips_expires_at(
+		&self,
+		tips_balance: &TipsBalance,
+		sender: &AccountId,
+	) -> Option<Timestamp> {
+		if tips_balance.get_account_id().is_some() {
+			return None
+		}
+
+		let server_id = tips_balance.get_tips_balance_info().get_server_id();
+		let expiry_days = self.refund_expiry_days.get(server_id)?;
+		let contribution = self.current_contribution(&tips_balance.key(), sender)?;
+		let contributed_at = contribution.get_contributed_at();
+
+		Some(contributed_at.saturating_add(expiry_days.saturating_mul(NANOSECONDS_PER_DAY)))
+	}
+
+	/// The sender's pro-rata share of the unclaimed balance. The last contributor receives the
+	/// remaining balance, so no dust is left behind.
+	pub(crate) fn refundable_amount(
+		&self,
+		tips_balance: &TipsBalance,
+		sender: &AccountId,
+	) -> Balance {
+		let key = tips_balance.key();
+		let contribution = self.contribution_of(&key, sender);
+		let total = self.contribution_pools.get(&key).unwrap_or_default().get_total();
+		let amount = tips_balance.get_amount();
+
+		if contribution.is_zero() {
+			return Zero::zero()
+		}
+
+		if contribution >= total {
+			return amount
+		}
+
+		self.proportion(amount, contribution, total)
+	}
+
+	pub(crate) fn internal_resolve_refund_expired_tips(
+		&mut self,
+		sender: AccountId,
+		tips_balance_info: TipsBalanceInfo,
+		amount: Balance,
+		contribution: Contribution,
+	) {
+		require!(env::promise_results_count() == 1);
+
+		let key = tips_balance_info.key();
+
+		match env::promise_result(0) {
+			PromiseResult::Successful(_) => {
+				let ft_identifier = tips_balance_info.get_ft_identifier();
+				let (symbol, formatted) = self.formatted_amount_of(ft_identifier, amount);
+				let reference = tips_balance_info.reference();
+
+				self.reduce_supporter_total(&key, &sender, contribution.get_amount());
+
+				log!("{} refunded {} {} from {}", sender, formatted, symbol, reference);
+			},
+			_ => {
+				if let Some(tips_balance) = self.tips_balances.get(&key) {
+					self.tips_balances.insert(&key, &tips_balance.add_balance(amount));
+				}
+
+				self.restore_contribution(&sender, &key, &contribution);
+			},
+		}
+	}
+}
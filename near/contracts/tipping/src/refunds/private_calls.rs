@@ -0,0 +1,15 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	#[private]
+	pub fn resolve_refund_expired_tips(
+		&mut self,
+		sender: AccountId,
+		tips_balance_info: TipsBalanceInfo,
+		amount: Balance,
+		contribution: Contribution,
+	) {
+		self.internal_resolve_refund_expired_tips(sender, tips_balance_info, amount, contribution);
+	}
+}
@@ -0,0 +1,197 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod refunds_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn refund_expired_tips_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Server enables refunds after one day
+		testing_env!(context.signer_account_id(accounts(4)).build());
+		contract.set_refund_expiry(Some(1));
+
+		// Send tips to user-user_id
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(30)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(10)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Refund after the expiry
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.block_timestamp(NANOSECONDS_PER_DAY)
+			.attached_deposit(1)
+			.build());
+		contract.refund_expired_tips(tips_balance_info.clone());
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info.clone()).unwrap();
+		let refundable_tip = contract.get_refundable_tip(tips_balance_info, accounts(2)).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 10);
+		assert_eq!(refundable_tip.amount, 10);
+		assert_eq!(refundable_tip.expires_at, Some(NANOSECONDS_PER_DAY));
+		assert!(refundable_tip.is_expired);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipsNotExpired")]
+	fn cant_refund_tips_before_expiry() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Server enables refunds after one day
+		testing_env!(context.signer_account_id(accounts(4)).build());
+		contract.set_refund_expiry(Some(1));
+
+		// Send tips to user-user_id
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(30)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(10)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Refund before the expiry
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.block_timestamp(NANOSECONDS_PER_DAY - 1)
+			.attached_deposit(1)
+			.build());
+		contract.refund_expired_tips(tips_balance_info);
+	}
+
+	#[test]
+	fn later_tips_dont_delay_expiry() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Server enables refunds after one day
+		testing_env!(context.signer_account_id(accounts(4)).build());
+		contract.set_refund_expiry(Some(1));
+
+		// Send tips to user-user_id
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(30)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(10)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Another sender tips just before the expiry
+		testing_env!(context
+			.signer_account_id(accounts(5))
+			.predecessor_account_id(accounts(5))
+			.block_timestamp(NANOSECONDS_PER_DAY - 1)
+			.attached_deposit(10)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Refund after the first sender's expiry
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.block_timestamp(NANOSECONDS_PER_DAY)
+			.attached_deposit(1)
+			.build());
+		contract.refund_expired_tips(tips_balance_info.clone());
+
+		// Test
+		let expires_at = contract.get_tips_expiry(tips_balance_info.clone(), accounts(5));
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 20);
+		assert_eq!(expires_at, Some(2 * NANOSECONDS_PER_DAY - 1));
+	}
+
+	#[test]
+	#[should_panic(expected = "NothingToRefund")]
+	fn cant_refund_tips_without_contribution() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Server enables refunds after one day
+		testing_env!(context.signer_account_id(accounts(4)).build());
+		contract.set_refund_expiry(Some(1));
+
+		// Send tips to user-user_id
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(30)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(10)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Refund by an account that never tipped
+		testing_env!(context
+			.signer_account_id(accounts(5))
+			.predecessor_account_id(accounts(5))
+			.block_timestamp(NANOSECONDS_PER_DAY)
+			.attached_deposit(1)
+			.build());
+		contract.refund_expired_tips(tips_balance_info);
+	}
+}
@@ -0,0 +1,88 @@
+use crate::*;
+
+pub type ContributionKey = (TipsBalanceKey, AccountId);
+
+/// Sum of the contributions to an unclaimed balance. The round is bumped whenever the balance is
+/// claimed, which invalidates every contribution made in an earlier round.
+#[derive(BorshDeserialize, BorshSerialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct ContributionPool {
+	round: u64,
+	total: Balance,
+}
+impl ContributionPool {
+	pub fn get_round(&self) -> u64 {
+		self.round
+	}
+
+	pub fn get_total(&self) -> Balance {
+		self.total
+	}
+
+	pub fn add(mut self, amount: Balance) -> Self {
+		self.total += amount;
+		self
+	}
+
+	pub fn remove(mut self, amount: Balance) -> Self {
+		self.total = self.total.saturating_sub(amount);
+		self
+	}
+
+	pub fn next_round(mut self) -> Self {
+		self.round += 1;
+		self.total = Zero::zero();
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Contribution {
+	round: u64,
+	amount: Balance,
+	contributed_at: Timestamp,
+}
+impl Contribution {
+	pub fn new(round: u64) -> Self {
+		Self { round, amount: Zero::zero(), contributed_at: env::block_timestamp() }
+	}
+
+	pub fn get_round(&self) -> u64 {
+		self.round
+	}
+
+	pub fn get_amount(&self) -> Balance {
+		self.amount
+	}
+
+	pub fn get_contributed_at(&self) -> Timestamp {
+		self.contributed_at
+	}
+
+	pub fn set_contributed_at(mut self, contributed_at: Timestamp) -> Self {
+		self.contributed_at = contributed_at;
+		self
+	}
+
+	pub fn add(mut self, amount: Balance) -> Self {
+		self.amount += amount;
+		self
+	}
+
+	pub fn remove(mut self, amount: Balance) -> Self {
+		self.amount = self.amount.saturating_sub(amount);
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RefundableTip {
+	pub tips_balance_info: TipsBalanceInfo,
+	pub sender: AccountId,
+	pub expires_at: Option<Timestamp>,
+	pub is_expired: bool,
+	pub amount: Balance,
+	pub symbol: String,
+	pub formatted_amount: String,
+}
@@ -0,0 +1,47 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_refund_expiry(&self, server_id: ServerId) -> Option<u64> {
+		self.refund_expiry_days.get(&server_id)
+	}
+
+	pub fn get_tips_expiry(
+		&self,
+		tips_balance_info: TipsBalanceInfo,
+		sender: AccountId,
+	) -> Option<Timestamp> {
+		let tips_balance = self.tips_balances.get(&tips_balance_info.key())?;
+
+		self.tips_expires_at(&tips_balance, &sender)
+	}
+
+	pub fn get_refundable_tip(
+		&self,
+		tips_balance_info: TipsBalanceInfo,
+		sender: AccountId,
+	) -> Option<RefundableTip> {
+		let tips_balance = self.tips_balances.get(&tips_balance_info.key())?;
+
+		if tips_balance.get_account_id().is_some() {
+			return None
+		}
+
+		let amount = self.refundable_amount(&tips_balance, &sender);
+		let expires_at = self.tips_expires_at(&tips_balance, &sender);
+		let is_expired =
+			matches!(expires_at, Some(expires_at) if env::block_timestamp() >= expires_at);
+		let ft_identifier = tips_balance_info.get_ft_identifier();
+		let (symbol, formatted_amount) = self.formatted_amount_of(ft_identifier, amount);
+
+		Some(RefundableTip {
+			tips_balance_info,
+			sender,
+			expires_at,
+			is_expired,
+			amount,
+			symbol,
+			formatted_amount,
+		})
+	}
+}
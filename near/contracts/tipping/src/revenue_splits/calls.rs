@@ -0,0 +1,37 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Sets the beneficiaries of every payout of a claimed reference, an empty list removes them.
+	/// Only the account the reference was claimed for can change it.
+	#[payable]
+	pub fn set_revenue_split(
+		&mut self,
+		server_id: ServerId,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+		beneficiaries: Vec<Beneficiary>,
+	) -> u64 {
+		assert_one_yocto();
+
+		let tips_balance_info =
+			TipsBalanceInfo::new(&server_id, &reference_type, &reference_id, "native");
+
+		self.assert_reference_owner(&tips_balance_info);
+		self.assert_beneficiaries(&beneficiaries);
+
+		let signer = env::signer_account_id();
+		let reference_key = self.reference_key(&tips_balance_info);
+		let version = match self.revenue_splits.get(&reference_key) {
+			Some(revenue_split) => revenue_split.get_version() + 1,
+			None => 1,
+		};
+		let revenue_split = RevenueSplit::new(version, beneficiaries, &signer);
+
+		self.revenue_splits.insert(&reference_key, &revenue_split);
+
+		log!("Revenue split of {} set to version {}", tips_balance_info.reference(), version);
+
+		version
+	}
+}
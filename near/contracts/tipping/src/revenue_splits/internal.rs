@@ -0,0 +1,98 @@
+use crate::*;
+
+impl Tipping {
+	/// The reference is owned by the account it was claimed for, which is always set on its
+	/// native balance by `claim_reference`.
+	pub(crate) fn reference_account_id(
+		&self,
+		tips_balance_info: &TipsBalanceInfo,
+	) -> Option<AccountId> {
+		let tips_balance_info = tips_balance_info.clone().set_ft_identifier("native");
+		let tips_balance = self.tips_balances.get(&tips_balance_info.key())?;
+
+		tips_balance.get_account_id().clone()
+	}
+
+	pub(crate) fn assert_reference_owner(&self, tips_balance_info: &TipsBalanceInfo) {
+		let account_id = self.reference_account_id(tips_balance_info).expect("ReceiverNotExists");
+
+		assert!(account_id == env::predecessor_account_id(), "Unauthorized");
+	}
+
+	pub(crate) fn assert_beneficiaries(&self, beneficiaries: &[Beneficiary]) {
+		assert!(beneficiaries.len() <= MAX_BENEFICIARIES, "TooManyBeneficiaries");
+
+		let mut total_weight: u32 = 0;
+
+		for (index, beneficiary) in beneficiaries.iter().enumerate() {
+			let account_id = &beneficiary.account_id;
+			let is_duplicate = beneficiaries
+				.iter()
+				.skip(index + 1)
+				.any(|other| &other.account_id == account_id);
+
+			assert!(beneficiary.weight > 0, "InvalidSplitWeight");
+			assert!(!is_duplicate, "DuplicateBeneficiary");
+
+			total_weight += beneficiary.weight as u32;
+		}
+
+		assert!(total_weight <= TOTAL_SPLIT_WEIGHT as u32, "InvalidSplitWeight");
+	}
+
+	/// Splits a payout of a tips balance between the beneficiaries of its reference and the
+	/// receiver, who also gets the rounding dust.
+	pub(crate) fn revenue_payouts(
+		&self,
+		tips_balance: &TipsBalance,
+		receiver: &AccountId,
+		amount: Balance,
+	) -> Vec<Payout> {
+		let reference_key = self.reference_key(tips_balance.get_tips_balance_info());
+		let beneficiaries = match self.revenue_splits.get(&reference_key) {
+			Some(revenue_split) => revenue_split.get_beneficiaries().clone(),
+			None => Vec::new(),
+		};
+
+		let mut payouts: Vec<Payout> = beneficiaries
+			.iter()
+			.map(|beneficiary| {
+				let weight = beneficiary.weight as Balance;
+				let share = self.proportion(amount, weight, TOTAL_SPLIT_WEIGHT as Balance);
+
+				Payout { account_id: beneficiary.account_id.clone(), amount: U128(share) }
+			})
+			.collect();
+		let shared: Balance = payouts.iter().map(|payout| payout.amount.0).sum();
+
+		payouts.insert(0, Payout { account_id: receiver.clone(), amount: U128(amount - shared) });
+		payouts.retain(|payout| !payout.amount.0.is_zero());
+
+		payouts
+	}
+
+	pub(crate) fn transfer_payouts(&mut self, ft_identifier: &str, payouts: &[Payout]) -> Promise {
+		let mut payouts = payouts.iter();
+		let payout = payouts.next().expect("NothingToClaimed");
+		let mut promise = self.transfer(&payout.account_id, ft_identifier, payout.amount.0);
+
+		for payout in payouts {
+			promise =
+				promise.and(self.transfer(&payout.account_id, ft_identifier, payout.amount.0));
+		}
+
+		promise
+	}
+
+	/// Returns the payouts whose transfers succeeded, reading the promise results from `offset`.
+	pub(crate) fn paid_payouts(&self, payouts: &[Payout], offset: u64) -> Vec<Payout> {
+		payouts
+			.iter()
+			.enumerate()
+			.filter(|(index, _)| {
+				matches!(env::promise_result(offset + *index as u64), PromiseResult::Successful(_))
+			})
+			.map(|(_, payout)| payout.clone())
+			.collect()
+	}
+}
@@ -0,0 +1,204 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod revenue_splits_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env, RuntimeFeesConfig, VMConfig,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	fn beneficiaries() -> Vec<Beneficiary> {
+		vec![
+			Beneficiary { account_id: accounts(5), weight: 3000 },
+			Beneficiary { account_id: accounts(1), weight: 1000 },
+		]
+	}
+
+	#[test]
+	fn set_revenue_split_bumps_version() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to post_1
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Reference is claimed for accounts(2)
+		let key = tips_balance_info.key();
+		let tips_balance = contract.tips_balances.get(&key).unwrap().set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&key, &tips_balance);
+
+		// Owner of the reference sets the revenue split twice
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(1).build());
+		contract.set_revenue_split(
+			accounts(4),
+			String::from("post"),
+			String::from("post_1"),
+			beneficiaries(),
+		);
+		let version = contract.set_revenue_split(
+			accounts(4),
+			String::from("post"),
+			String::from("post_1"),
+			Vec::new(),
+		);
+
+		// Test
+		let revenue_split = contract
+			.get_revenue_split(accounts(4), String::from("post"), String::from("post_1"))
+			.unwrap();
+
+		assert_eq!(version, 2);
+		assert_eq!(revenue_split.get_version(), 2);
+		assert!(revenue_split.get_beneficiaries().is_empty());
+	}
+
+	#[test]
+	#[should_panic(expected = "Unauthorized")]
+	fn cant_set_revenue_split_when_not_reference_owner() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to post_1
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Reference is claimed for accounts(2)
+		let key = tips_balance_info.key();
+		let tips_balance = contract.tips_balances.get(&key).unwrap().set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&key, &tips_balance);
+
+		// Another account sets the revenue split
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.set_revenue_split(
+			accounts(4),
+			String::from("post"),
+			String::from("post_1"),
+			beneficiaries(),
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "InvalidSplitWeight")]
+	fn cant_set_revenue_split_over_total_weight() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to post_1
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Reference is claimed for accounts(2)
+		let key = tips_balance_info.key();
+		let tips_balance = contract.tips_balances.get(&key).unwrap().set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&key, &tips_balance);
+
+		// Payload
+		let beneficiaries = vec![
+			Beneficiary { account_id: accounts(5), weight: 6000 },
+			Beneficiary { account_id: accounts(1), weight: 4001 },
+		];
+
+		// Owner of the reference sets the revenue split
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(1).build());
+		contract.set_revenue_split(
+			accounts(4),
+			String::from("post"),
+			String::from("post_1"),
+			beneficiaries,
+		);
+	}
+
+	#[test]
+	fn claim_tip_fans_out_to_beneficiaries() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Send tip to post_1
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Reference is claimed for accounts(2)
+		let key = tips_balance_info.key();
+		let tips_balance = contract.tips_balances.get(&key).unwrap().set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&key, &tips_balance);
+
+		// Owner of the reference sets the revenue split
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(1).build());
+		contract.set_revenue_split(
+			accounts(4),
+			String::from("post"),
+			String::from("post_1"),
+			beneficiaries(),
+		);
+
+		// Test payouts
+		let tips_balance = contract.tips_balances.get(&tips_balance_info.key()).unwrap();
+		let payouts = contract.revenue_payouts(&tips_balance, &accounts(2), 100);
+
+		assert_eq!(
+			payouts,
+			vec![
+				Payout { account_id: accounts(2), amount: U128(60) },
+				Payout { account_id: accounts(5), amount: U128(30) },
+				Payout { account_id: accounts(1), amount: U128(10) },
+			]
+		);
+
+		// accounts(2) claims
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.claim_tip(tips_balance_info.clone());
+
+		// Resolve the claim with a failed transfer to accounts(1)
+		testing_env!(
+			context.predecessor_account_id(accounts(0)).build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![
+				PromiseResult::Successful(Vec::new()),
+				PromiseResult::Successful(Vec::new()),
+				PromiseResult::Failed,
+			],
+		);
+		contract.resolve_claim_tip(tips_balance, payouts);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 10);
+	}
+}
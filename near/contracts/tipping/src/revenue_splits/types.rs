@@ -0,0 +1,54 @@
+use crate::*;
+
+pub const MAX_BENEFICIARIES: usize = 10;
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Beneficiary {
+	pub account_id: AccountId,
+	pub weight: u16, // basis points
+}
+
+/// Shares of every payout of a claimed reference that go to beneficiaries, the rest goes to the
+/// account of the reference. The version is bumped on every change.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RevenueSplit {
+	version: u64,
+	beneficiaries: Vec<Beneficiary>,
+	updated_by: AccountId,
+	updated_at: Timestamp,
+}
+impl RevenueSplit {
+	pub fn new(version: u64, beneficiaries: Vec<Beneficiary>, updated_by: &AccountId) -> Self {
+		Self {
+			version,
+			beneficiaries,
+			updated_by: updated_by.clone(),
+			updated_at: env::block_timestamp(),
+		}
+	}
+
+	pub fn get_version(&self) -> u64 {
+		self.version
+	}
+
+	pub fn get_beneficiaries(&self) -> &Vec<Beneficiary> {
+		&self.beneficiaries
+	}
+
+	pub fn get_updated_by(&self) -> &AccountId {
+		&self.updated_by
+	}
+
+	pub fn get_updated_at(&self) -> Timestamp {
+		self.updated_at
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Payout {
+	pub account_id: AccountId,
+	pub amount: U128,
+}
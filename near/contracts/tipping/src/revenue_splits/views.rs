@@ -0,0 +1,13 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_revenue_split(
+		&self,
+		server_id: ServerId,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+	) -> Option<RevenueSplit> {
+		self.revenue_splits.get(&(server_id, reference_type, reference_id))
+	}
+}
@@ -0,0 +1,68 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Streams an amount of the deposit of the caller to a reference at a rate per second.
+	#[payable]
+	pub fn create_stream(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		rate_per_second: U128,
+		amount: U128,
+	) -> StreamId {
+		let sender = env::predecessor_account_id();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let sender_index = StreamIndex::Sender(sender.clone());
+		let reference_index = StreamIndex::Reference(tips_balance_info.key());
+
+		assert!(rate_per_second.0 > 0, "InvalidStreamRate");
+		assert!(amount.0 >= rate_per_second.0, "InvalidStreamRate");
+		assert!(self.stream_ids(&sender_index).len() < MAX_STREAMS_PER_SENDER, "TooManyStreams");
+		assert!(
+			self.stream_ids(&reference_index).len() < MAX_STREAMS_PER_REFERENCE,
+			"TooManyStreams"
+		);
+		assert!(self.ft_identifiers.get(&ft_identifier).is_some(), "TokenNotFound");
+		self.assert_not_paused(PauseFeature::Tipping, Some(&ft_identifier));
+		self.assert_not_blocked(&sender, &tips_balance_info);
+
+		self.add_attached_deposit(&sender);
+
+		self.remove_deposit(&sender, &ft_identifier, amount.0);
+
+		let id = self.next_stream_id;
+		let stream = Stream::new(id, &sender, &tips_balance_info, rate_per_second.0, amount.0);
+
+		self.next_stream_id += 1;
+		self.streams.insert(&id, &stream);
+		self.add_stream_id(&sender_index, id);
+		self.add_stream_id(&reference_index, id);
+
+		log!("{} started streaming to {}", sender, tips_balance_info.reference());
+
+		id
+	}
+
+	/// Settles what has been streamed and gives the rest of the deposit back to the sender.
+	pub fn stop_stream(&mut self, stream_id: StreamId) -> U128 {
+		let stream = self.streams.get(&stream_id).expect("StreamNotExists");
+		let sender = env::predecessor_account_id();
+
+		let ft_identifier = stream.get_tips_balance_info().get_ft_identifier().to_string();
+
+		assert!(stream.get_sender() == &sender, "Unauthorized");
+		self.assert_not_paused(PauseFeature::Claiming, Some(&ft_identifier));
+
+		let stream = self.settle_stream(stream);
+		let remainder = stream.get_deposit() - stream.get_settled();
+
+		if !stream.is_exhausted() {
+			self.remove_stream(&stream);
+			self.add_deposit(&sender, &ft_identifier, remainder);
+		}
+
+		log!("Stream {} stopped", stream_id);
+
+		U128(remainder)
+	}
+}
@@ -0,0 +1,101 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn stream_ids(&self, index: &StreamIndex) -> Vec<StreamId> {
+		self.stream_ids.get(index).unwrap_or_default()
+	}
+
+	pub(crate) fn add_stream_id(&mut self, index: &StreamIndex, id: StreamId) {
+		let mut stream_ids = self.stream_ids(index);
+
+		stream_ids.push(id);
+
+		self.stream_ids.insert(index, &stream_ids);
+	}
+
+	pub(crate) fn remove_stream_id(&mut self, index: &StreamIndex, id: StreamId) {
+		let mut stream_ids = self.stream_ids(index);
+
+		stream_ids.retain(|stream_id| *stream_id != id);
+
+		if stream_ids.is_empty() {
+			self.stream_ids.remove(index);
+		} else {
+			self.stream_ids.insert(index, &stream_ids);
+		}
+	}
+
+	pub(crate) fn streams_by_index(&self, index: &StreamIndex) -> Vec<Stream> {
+		self.stream_ids(index).iter().filter_map(|id| self.streams.get(id)).collect()
+	}
+
+	pub(crate) fn remove_stream(&mut self, stream: &Stream) {
+		let id = stream.get_id();
+		let sender_index = StreamIndex::Sender(stream.get_sender().clone());
+		let reference_index = StreamIndex::Reference(stream.get_tips_balance_info().key());
+
+		self.streams.remove(&id);
+		self.remove_stream_id(&sender_index, id);
+		self.remove_stream_id(&reference_index, id);
+	}
+
+	pub(crate) fn stream_with_status(&self, stream: Stream) -> StreamWithStatus {
+		let now = env::block_timestamp();
+
+		StreamWithStatus {
+			streamed: U128(stream.streamed_at(now)),
+			unsettled: U128(stream.unsettled_at(now)),
+			stops_at: stream.get_stops_at(),
+			stream,
+		}
+	}
+
+	/// Adds what the streams to a reference have streamed but not settled yet, for views.
+	pub(crate) fn with_streamed(&self, tips_balance: TipsBalance) -> TipsBalance {
+		let index = StreamIndex::Reference(tips_balance.key());
+		let now = env::block_timestamp();
+		let unsettled: Balance = self
+			.streams_by_index(&index)
+			.iter()
+			.map(|stream| stream.unsettled_at(now))
+			.sum();
+		let total_amount = tips_balance.get_total_amount() + unsettled;
+
+		tips_balance.add_balance(unsettled).set_total_amount(total_amount)
+	}
+
+	pub(crate) fn settle_stream(&mut self, stream: Stream) -> Stream {
+		let amount = stream.unsettled_at(env::block_timestamp());
+
+		if amount.is_zero() {
+			return stream
+		}
+
+		let stream = stream.settle(amount);
+
+		self.create_tips_balance(
+			stream.get_sender(),
+			stream.get_tips_balance_info(),
+			&amount,
+			None,
+			TipKind::Credit,
+		);
+
+		if stream.is_exhausted() {
+			self.remove_stream(&stream);
+		} else {
+			self.streams.insert(&stream.get_id(), &stream);
+		}
+
+		stream
+	}
+
+	/// Writes the streamed amounts of every stream to a reference into its `TipsBalance`.
+	pub(crate) fn settle_streams(&mut self, tips_balance_key: &TipsBalanceKey) {
+		let index = StreamIndex::Reference(tips_balance_key.clone());
+
+		for stream in self.streams_by_index(&index) {
+			self.settle_stream(stream);
+		}
+	}
+}
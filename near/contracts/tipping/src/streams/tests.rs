@@ -0,0 +1,184 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod streams_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn get_tips_balance_includes_streamed_amount() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) streams 10 per second to post_1 for 100 seconds
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1000).build());
+		let stream_id = contract.create_stream(tips_balance_info.clone(), U128(10), U128(1000));
+
+		// 30 seconds later
+		testing_env!(context.block_timestamp(30 * NANOSECONDS_PER_SECOND).build());
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+		let stream = contract.get_stream(stream_id).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 300);
+		assert_eq!(stream.unsettled, U128(300));
+		assert_eq!(stream.stops_at, 100 * NANOSECONDS_PER_SECOND);
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(0));
+	}
+
+	#[test]
+	fn streamed_amount_is_capped_by_deposit() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) streams 10 per second to post_1 for 100 seconds
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1000).build());
+		contract.create_stream(tips_balance_info.clone(), U128(10), U128(1000));
+
+		// 200 seconds later
+		testing_env!(context.block_timestamp(200 * NANOSECONDS_PER_SECOND).build());
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 1000);
+	}
+
+	#[test]
+	fn stop_stream_settles_and_returns_remainder() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) streams 10 per second to post_1 for 100 seconds
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1000).build());
+		let stream_id = contract.create_stream(tips_balance_info.clone(), U128(10), U128(1000));
+
+		// accounts(3) stops the stream 40 seconds later
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(0)
+			.block_timestamp(40 * NANOSECONDS_PER_SECOND)
+			.build());
+		let remainder = contract.stop_stream(stream_id);
+
+		// Test
+		let tips_balance = contract.tips_balances.get(&tips_balance_info.key()).unwrap();
+
+		assert_eq!(remainder, U128(600));
+		assert_eq!(tips_balance.get_amount(), 400);
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(600));
+		assert!(contract.get_stream(stream_id).is_none());
+		assert!(contract.get_streams_by_sender(accounts(3)).is_empty());
+	}
+
+	#[test]
+	#[should_panic(expected = "Unauthorized")]
+	fn cant_stop_stream_of_other_sender() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) streams 10 per second to post_1 for 100 seconds
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1000).build());
+		let stream_id = contract.create_stream(tips_balance_info.clone(), U128(10), U128(1000));
+
+		// accounts(2) stops the stream
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(0).build());
+		contract.stop_stream(stream_id);
+	}
+
+	#[test]
+	fn claim_tip_settles_streamed_amount() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) streams 10 per second to post_1 for 100 seconds
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1000).build());
+		let stream_id = contract.create_stream(tips_balance_info.clone(), U128(10), U128(1000));
+
+		// Reference is claimed for accounts(2)
+		let key = tips_balance_info.key();
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&key, &tips_balance);
+
+		// accounts(2) claims 30 seconds later
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.block_timestamp(30 * NANOSECONDS_PER_SECOND)
+			.build());
+		contract.claim_tip(tips_balance_info);
+
+		// Test
+		let stream = contract.get_stream(stream_id).unwrap();
+		let tips_balance = contract.tips_balances.get(&key).unwrap();
+
+		assert_eq!(tips_balance.get_amount(), 0);
+		assert_eq!(tips_balance.get_total_amount(), 300);
+		assert_eq!(stream.stream.get_settled(), 300);
+		assert_eq!(stream.unsettled, U128(0));
+	}
+
+	#[test]
+	#[should_panic(expected = "TipNotCancellable")]
+	fn cant_cancel_settled_stream() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) streams 10 per second to post_1 for 100 seconds
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1000).build());
+		let stream_id = contract.create_stream(tips_balance_info.clone(), U128(10), U128(1000));
+
+		// accounts(3) stops the stream 30 seconds later
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(0)
+			.block_timestamp(30 * NANOSECONDS_PER_SECOND)
+			.build());
+		contract.stop_stream(stream_id);
+
+		// accounts(3) cancels the settled amount
+		let tip_records = contract.get_tip_records_by_sender(accounts(3), None, None);
+		let tip_id = tip_records.data[0].tip_record.get_id();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+	}
+}
@@ -0,0 +1,112 @@
+use crate::*;
+
+pub type StreamId = u64;
+
+pub const MAX_STREAMS_PER_REFERENCE: usize = 10;
+pub const MAX_STREAMS_PER_SENDER: usize = 20;
+
+/// Tips flowing to a reference every second until the deposit runs out. The streamed amount is
+/// computed from the block timestamp and only written to the `TipsBalance` when it is settled.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Stream {
+	id: StreamId,
+	sender: AccountId,
+	tips_balance_info: TipsBalanceInfo,
+	rate_per_second: U128,
+	deposit: U128,
+	settled: U128,
+	started_at: Timestamp,
+}
+impl Stream {
+	pub fn new(
+		id: StreamId,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		rate_per_second: Balance,
+		deposit: Balance,
+	) -> Self {
+		Self {
+			id,
+			sender: sender.clone(),
+			tips_balance_info: tips_balance_info.clone(),
+			rate_per_second: U128(rate_per_second),
+			deposit: U128(deposit),
+			settled: U128(Zero::zero()),
+			started_at: env::block_timestamp(),
+		}
+	}
+
+	pub fn get_id(&self) -> StreamId {
+		self.id
+	}
+
+	pub fn get_sender(&self) -> &AccountId {
+		&self.sender
+	}
+
+	pub fn get_tips_balance_info(&self) -> &TipsBalanceInfo {
+		&self.tips_balance_info
+	}
+
+	pub fn get_rate_per_second(&self) -> Balance {
+		self.rate_per_second.0
+	}
+
+	pub fn get_deposit(&self) -> Balance {
+		self.deposit.0
+	}
+
+	pub fn get_settled(&self) -> Balance {
+		self.settled.0
+	}
+
+	pub fn get_started_at(&self) -> Timestamp {
+		self.started_at
+	}
+
+	/// Timestamp at which the whole deposit has been streamed.
+	pub fn get_stops_at(&self) -> Timestamp {
+		let seconds = self.get_deposit() / self.get_rate_per_second();
+		let nanoseconds = (seconds as u64).saturating_mul(NANOSECONDS_PER_SECOND);
+
+		self.started_at.saturating_add(nanoseconds)
+	}
+
+	/// Amount streamed since the start, including the settled amount.
+	pub fn streamed_at(&self, now: Timestamp) -> Balance {
+		let elapsed = now.saturating_sub(self.started_at);
+		let streamed = U256::from(self.get_rate_per_second()) * U256::from(elapsed) /
+			U256::from(NANOSECONDS_PER_SECOND);
+
+		min(streamed, U256::from(self.get_deposit())).as_u128()
+	}
+
+	pub fn unsettled_at(&self, now: Timestamp) -> Balance {
+		self.streamed_at(now) - self.get_settled()
+	}
+
+	pub fn is_exhausted(&self) -> bool {
+		self.get_settled() == self.get_deposit()
+	}
+
+	pub fn settle(mut self, amount: Balance) -> Self {
+		self.settled = U128(self.get_settled() + amount);
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, Eq)]
+pub enum StreamIndex {
+	Reference(TipsBalanceKey),
+	Sender(AccountId),
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StreamWithStatus {
+	pub stream: Stream,
+	pub streamed: U128,
+	pub unsettled: U128,
+	pub stops_at: Timestamp,
+}
@@ -0,0 +1,25 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_stream(&self, stream_id: StreamId) -> Option<StreamWithStatus> {
+		self.streams.get(&stream_id).map(|stream| self.stream_with_status(stream))
+	}
+
+	pub fn get_streams_by_sender(&self, sender: AccountId) -> Vec<StreamWithStatus> {
+		self.streams_by_index(&StreamIndex::Sender(sender))
+			.into_iter()
+			.map(|stream| self.stream_with_status(stream))
+			.collect()
+	}
+
+	pub fn get_streams_by_reference(
+		&self,
+		tips_balance_info: TipsBalanceInfo,
+	) -> Vec<StreamWithStatus> {
+		self.streams_by_index(&StreamIndex::Reference(tips_balance_info.key()))
+			.into_iter()
+			.map(|stream| self.stream_with_status(stream))
+			.collect()
+	}
+}
@@ -0,0 +1,122 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Subscribes the caller to tip a reference every period until the end date, paid from its
+	/// deposit.
+	#[payable]
+	pub fn create_subscription(
+		&mut self,
+		tips_balance_info: TipsBalanceInfo,
+		amount: U128,
+		period_seconds: u64,
+		ends_at: Option<Timestamp>,
+	) -> SubscriptionId {
+		let sender = env::predecessor_account_id();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let sender_index = SubscriptionIndex::Sender(sender.clone());
+		let reference_index = SubscriptionIndex::Reference(tips_balance_info.key());
+		let total_subscriptions = self.subscription_ids(&sender_index).len();
+		let total_reference_subscriptions = self.subscription_ids(&reference_index).len();
+		let period = period_seconds.saturating_mul(NANOSECONDS_PER_SECOND);
+
+		assert!(amount.0 > 0, "NothingToTip");
+		assert!(period >= MIN_SUBSCRIPTION_PERIOD, "InvalidPeriod");
+		assert!(total_subscriptions < MAX_SUBSCRIPTIONS_PER_SENDER, "TooManySubscriptions");
+		assert!(
+			total_reference_subscriptions < MAX_SUBSCRIPTIONS_PER_REFERENCE,
+			"TooManySubscriptions"
+		);
+		assert!(self.ft_identifiers.get(&ft_identifier).is_some(), "TokenNotFound");
+		self.assert_not_blocked(&sender, &tips_balance_info);
+
+		if let Some(ends_at) = ends_at {
+			assert!(ends_at > env::block_timestamp(), "InvalidEndDate");
+		}
+
+		self.add_attached_deposit(&sender);
+
+		let (subscription_config, id) = self.subscription_config.clone().next_subscription_id();
+		let subscription =
+			Subscription::new(id, &sender, &tips_balance_info, amount.0, period_seconds, ends_at);
+
+		self.subscription_config = subscription_config;
+		self.subscriptions.insert(&id, &subscription);
+		self.add_subscription_id(&sender_index, id);
+		self.add_subscription_id(&reference_index, id);
+
+		id
+	}
+
+	pub fn cancel_subscription(&mut self, subscription_id: SubscriptionId) {
+		let subscription = self.subscriptions.get(&subscription_id).expect("SubscriptionNotExists");
+
+		assert!(subscription.get_sender() == &env::predecessor_account_id(), "Unauthorized");
+
+		self.remove_subscription(&subscription);
+
+		log!("Subscription {} cancelled", subscription_id);
+	}
+
+	/// Checks up to `limit` subscriptions, continuing where the previous call stopped, and pays
+	/// the due ones. The caller earns the keeper reward of every payment in its deposit. Returns
+	/// the number of payments made.
+	pub fn execute_due_subscriptions(&mut self, limit: u64) -> u64 {
+		self.assert_not_paused(PauseFeature::Tipping, None);
+
+		let keeper = env::predecessor_account_id();
+		let now = env::block_timestamp();
+		let total_subscriptions = self.subscriptions.len();
+		let limit = min(limit, total_subscriptions);
+		let cursor = self.subscription_config.get_cursor();
+		let values = self.subscriptions.values_as_vector();
+		let subscriptions: Vec<Subscription> = (0..limit)
+			.filter_map(|offset| values.get((cursor + offset) % total_subscriptions))
+			.collect();
+		let mut ended = Vec::<Subscription>::new();
+		let mut payment_count = 0;
+
+		for subscription in subscriptions {
+			if subscription.is_ended() {
+				ended.push(subscription);
+				continue
+			}
+
+			if !subscription.is_due(now) {
+				continue
+			}
+
+			let payments = subscription.get_payment_count();
+			let subscription = self.execute_subscription(subscription, &keeper);
+
+			if subscription.get_payment_count() > payments {
+				payment_count += 1;
+			}
+
+			self.subscriptions.insert(&subscription.get_id(), &subscription);
+		}
+
+		if total_subscriptions > 0 {
+			let cursor = (cursor + limit) % total_subscriptions;
+
+			self.subscription_config = self.subscription_config.clone().set_cursor(cursor);
+		}
+
+		for subscription in ended {
+			self.remove_subscription(&subscription);
+
+			log!("Subscription {} ended", subscription.get_id());
+		}
+
+		payment_count
+	}
+
+	pub fn set_keeper_reward(&mut self, keeper_reward: u16) {
+		self.assert_owner();
+
+		assert!(keeper_reward <= TOTAL_SPLIT_WEIGHT, "InvalidKeeperReward");
+
+		self.subscription_config =
+			self.subscription_config.clone().set_keeper_reward(keeper_reward);
+	}
+}
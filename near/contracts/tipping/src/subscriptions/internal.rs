@@ -0,0 +1,80 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn subscription_ids(&self, index: &SubscriptionIndex) -> Vec<SubscriptionId> {
+		self.subscription_ids.get(index).unwrap_or_default()
+	}
+
+	pub(crate) fn add_subscription_id(&mut self, index: &SubscriptionIndex, id: SubscriptionId) {
+		let mut subscription_ids = self.subscription_ids(index);
+
+		subscription_ids.push(id);
+
+		self.subscription_ids.insert(index, &subscription_ids);
+	}
+
+	pub(crate) fn remove_subscription_id(&mut self, index: &SubscriptionIndex, id: SubscriptionId) {
+		let mut subscription_ids = self.subscription_ids(index);
+
+		subscription_ids.retain(|subscription_id| *subscription_id != id);
+
+		if subscription_ids.is_empty() {
+			self.subscription_ids.remove(index);
+		} else {
+			self.subscription_ids.insert(index, &subscription_ids);
+		}
+	}
+
+	pub(crate) fn remove_subscription(&mut self, subscription: &Subscription) {
+		let id = subscription.get_id();
+		let sender_index = SubscriptionIndex::Sender(subscription.get_sender().clone());
+		let reference_index =
+			SubscriptionIndex::Reference(subscription.get_tips_balance_info().key());
+
+		self.subscriptions.remove(&id);
+		self.remove_subscription_id(&sender_index, id);
+		self.remove_subscription_id(&reference_index, id);
+	}
+
+	pub(crate) fn subscriptions_by_index(&self, index: &SubscriptionIndex) -> Vec<Subscription> {
+		self.subscription_ids(index)
+			.iter()
+			.filter_map(|id| self.subscriptions.get(id))
+			.collect()
+	}
+
+	/// Pays one period of a due subscription from the deposit of the sender, plus the keeper
+	/// reward. A payment that cannot be made is skipped and counted as missed.
+	pub(crate) fn execute_subscription(
+		&mut self,
+		subscription: Subscription,
+		keeper: &AccountId,
+	) -> Subscription {
+		let sender = subscription.get_sender().clone();
+		let tips_balance_info = subscription.get_tips_balance_info().clone();
+		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
+		let amount = subscription.get_amount();
+		let keeper_reward = self.subscription_config.get_keeper_reward() as Balance;
+		let reward = self.proportion(amount, keeper_reward, TOTAL_SPLIT_WEIGHT as Balance);
+
+		let reason = if self.deposit_of(&sender, &ft_identifier) < amount + reward {
+			Some("InsufficientDeposit")
+		} else {
+			self.tip_rejection_reason(&sender, &tips_balance_info, amount)
+		};
+
+		if let Some(reason) = reason {
+			log!("{}: subscription {} missed a payment", reason, subscription.get_id());
+			return subscription.missed()
+		}
+
+		self.internal_tip_from_balance(&sender, &tips_balance_info, amount, None, TipKind::Settled);
+
+		if !reward.is_zero() {
+			self.remove_deposit(&sender, &ft_identifier, reward);
+			self.add_deposit(keeper, &ft_identifier, reward);
+		}
+
+		subscription.paid()
+	}
+}
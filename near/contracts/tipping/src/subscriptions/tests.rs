@@ -0,0 +1,228 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod subscriptions_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	const PERIOD_SECONDS: u64 = 60 * 60 * 24;
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn execute_due_subscriptions_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) subscribes to post_1 and escrows the payments
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(25_000).build());
+		let subscription_id = contract.create_subscription(
+			tips_balance_info.clone(),
+			U128(10_000),
+			PERIOD_SECONDS,
+			None,
+		);
+
+		// A keeper executes the due subscriptions twice in the same period
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(0).build());
+		let first_payments = contract.execute_due_subscriptions(10);
+		let second_payments = contract.execute_due_subscriptions(10);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
+		let subscription = contract.get_subscription(subscription_id).unwrap();
+
+		assert_eq!(first_payments, 1);
+		assert_eq!(second_payments, 0);
+		assert_eq!(tips_balance.tips_balance.get_amount(), 10_000);
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(14_950));
+		assert_eq!(contract.get_deposit(accounts(5), String::from("native")), U128(50));
+		assert_eq!(subscription.get_next_payment_at(), PERIOD_SECONDS * NANOSECONDS_PER_SECOND);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipNotCancellable")]
+	fn cant_cancel_subscription_payment() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) subscribes to post_1 and escrows the payments
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(25_000).build());
+		contract.create_subscription(tips_balance_info.clone(), U128(10_000), PERIOD_SECONDS, None);
+
+		// A keeper executes the due subscription
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(0).build());
+		contract.execute_due_subscriptions(10);
+
+		// Test
+		let tip_record = &contract.get_tip_records_by_sender(accounts(3), None, None).data[0];
+		let tip_id = tip_record.tip_record.get_id();
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+	}
+
+	#[test]
+	fn subscription_misses_payment_without_deposit() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) subscribes to post_1 and escrows the payments
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(25_000).build());
+		let subscription_id = contract.create_subscription(
+			tips_balance_info.clone(),
+			U128(10_000),
+			PERIOD_SECONDS,
+			None,
+		);
+
+		// A keeper executes the subscription for three periods
+		for period in 0..3 {
+			let now = period * PERIOD_SECONDS * NANOSECONDS_PER_SECOND;
+
+			testing_env!(context
+				.predecessor_account_id(accounts(5))
+				.attached_deposit(0)
+				.block_timestamp(now)
+				.build());
+			contract.execute_due_subscriptions(10);
+		}
+
+		// Test
+		let subscription = contract.get_subscription(subscription_id).unwrap();
+
+		assert_eq!(subscription.get_payment_count(), 2);
+		assert_eq!(subscription.get_missed_count(), 1);
+	}
+
+	#[test]
+	fn ended_subscription_is_removed() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) subscribes to post_1 and escrows the payments
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(25_000).build());
+		let subscription_id = contract.create_subscription(
+			tips_balance_info.clone(),
+			U128(10_000),
+			PERIOD_SECONDS,
+			Some(1),
+		);
+
+		// A keeper executes the subscription twice
+		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(0).build());
+		contract.execute_due_subscriptions(10);
+		contract.execute_due_subscriptions(10);
+
+		// Test
+		assert!(contract.get_subscription(subscription_id).is_none());
+		assert!(contract.get_subscriptions_by_sender(accounts(3)).is_empty());
+	}
+
+	#[test]
+	fn cancel_subscription_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) subscribes to post_1 and escrows the payments
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(25_000).build());
+		let subscription_id = contract.create_subscription(
+			tips_balance_info.clone(),
+			U128(10_000),
+			PERIOD_SECONDS,
+			None,
+		);
+
+		assert_eq!(contract.get_subscriptions_by_reference(tips_balance_info.clone()).len(), 1);
+
+		// accounts(3) cancels the subscription
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
+		contract.cancel_subscription(subscription_id);
+
+		// Test
+		assert!(contract.get_subscription(subscription_id).is_none());
+		assert!(contract.get_subscriptions_by_reference(tips_balance_info).is_empty());
+	}
+
+	#[test]
+	#[should_panic(expected = "TooManySubscriptions")]
+	fn cant_subscribe_to_full_reference() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) subscribes to post_1 and escrows the payments
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(25_000).build());
+		contract.create_subscription(tips_balance_info.clone(), U128(10_000), PERIOD_SECONDS, None);
+
+		// Test
+		for sender in [accounts(1), accounts(2), accounts(5)] {
+			testing_env!(context.predecessor_account_id(sender).attached_deposit(0).build());
+
+			for _ in 0..MAX_SUBSCRIPTIONS_PER_SENDER {
+				contract.create_subscription(
+					tips_balance_info.clone(),
+					U128(10_000),
+					PERIOD_SECONDS,
+					None,
+				);
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Unauthorized")]
+	fn cant_cancel_subscription_of_other_sender() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) subscribes to post_1 and escrows the payments
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(25_000).build());
+		let subscription_id = contract.create_subscription(
+			tips_balance_info.clone(),
+			U128(10_000),
+			PERIOD_SECONDS,
+			None,
+		);
+
+		// accounts(2) cancels the subscription
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(0).build());
+		contract.cancel_subscription(subscription_id);
+	}
+}
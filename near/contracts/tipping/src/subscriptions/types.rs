@@ -0,0 +1,142 @@
+use crate::*;
+
+pub type SubscriptionId = u64;
+
+pub const MAX_SUBSCRIPTIONS_PER_SENDER: usize = 20;
+pub const MAX_SUBSCRIPTIONS_PER_REFERENCE: usize = 50;
+pub const MIN_SUBSCRIPTION_PERIOD: u64 = 60 * 60 * NANOSECONDS_PER_SECOND;
+pub const DEFAULT_KEEPER_REWARD: u16 = 50; // basis points
+
+/// Recurring tip paid from the deposit of the sender once per period, until the end date.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Subscription {
+	id: SubscriptionId,
+	sender: AccountId,
+	tips_balance_info: TipsBalanceInfo,
+	amount: U128,
+	period: u64,
+	next_payment_at: Timestamp,
+	ends_at: Option<Timestamp>,
+	payment_count: u64,
+	missed_count: u64,
+}
+impl Subscription {
+	pub fn new(
+		id: SubscriptionId,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+		period_seconds: u64,
+		ends_at: Option<Timestamp>,
+	) -> Self {
+		Self {
+			id,
+			sender: sender.clone(),
+			tips_balance_info: tips_balance_info.clone(),
+			amount: U128(amount),
+			period: period_seconds.saturating_mul(NANOSECONDS_PER_SECOND),
+			next_payment_at: env::block_timestamp(),
+			ends_at,
+			payment_count: Zero::zero(),
+			missed_count: Zero::zero(),
+		}
+	}
+
+	pub fn get_id(&self) -> SubscriptionId {
+		self.id
+	}
+
+	pub fn get_sender(&self) -> &AccountId {
+		&self.sender
+	}
+
+	pub fn get_tips_balance_info(&self) -> &TipsBalanceInfo {
+		&self.tips_balance_info
+	}
+
+	pub fn get_amount(&self) -> Balance {
+		self.amount.0
+	}
+
+	pub fn get_period(&self) -> u64 {
+		self.period
+	}
+
+	pub fn get_next_payment_at(&self) -> Timestamp {
+		self.next_payment_at
+	}
+
+	pub fn get_payment_count(&self) -> u64 {
+		self.payment_count
+	}
+
+	pub fn get_missed_count(&self) -> u64 {
+		self.missed_count
+	}
+
+	pub fn is_due(&self, now: Timestamp) -> bool {
+		now >= self.next_payment_at && !self.is_ended()
+	}
+
+	/// A subscription ends once its next payment falls after the end date.
+	pub fn is_ended(&self) -> bool {
+		matches!(self.ends_at, Some(ends_at) if self.next_payment_at > ends_at)
+	}
+
+	pub fn paid(mut self) -> Self {
+		self.payment_count += 1;
+		self.next_payment_at += self.period;
+		self
+	}
+
+	pub fn missed(mut self) -> Self {
+		self.missed_count += 1;
+		self.next_payment_at += self.period;
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, Eq)]
+pub enum SubscriptionIndex {
+	Reference(TipsBalanceKey),
+	Sender(AccountId),
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SubscriptionConfig {
+	next_subscription_id: SubscriptionId,
+	keeper_reward: u16,
+	cursor: u64,
+}
+impl Default for SubscriptionConfig {
+	fn default() -> Self {
+		Self { next_subscription_id: 0, keeper_reward: DEFAULT_KEEPER_REWARD, cursor: 0 }
+	}
+}
+impl SubscriptionConfig {
+	pub fn get_keeper_reward(&self) -> u16 {
+		self.keeper_reward
+	}
+
+	pub fn get_cursor(&self) -> u64 {
+		self.cursor
+	}
+
+	pub fn next_subscription_id(mut self) -> (Self, SubscriptionId) {
+		let subscription_id = self.next_subscription_id;
+		self.next_subscription_id += 1;
+		(self, subscription_id)
+	}
+
+	pub fn set_keeper_reward(mut self, keeper_reward: u16) -> Self {
+		self.keeper_reward = keeper_reward;
+		self
+	}
+
+	pub fn set_cursor(mut self, cursor: u64) -> Self {
+		self.cursor = cursor;
+		self
+	}
+}
@@ -0,0 +1,23 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_subscription(&self, subscription_id: SubscriptionId) -> Option<Subscription> {
+		self.subscriptions.get(&subscription_id)
+	}
+
+	pub fn get_subscriptions_by_sender(&self, sender: AccountId) -> Vec<Subscription> {
+		self.subscriptions_by_index(&SubscriptionIndex::Sender(sender))
+	}
+
+	pub fn get_subscriptions_by_reference(
+		&self,
+		tips_balance_info: TipsBalanceInfo,
+	) -> Vec<Subscription> {
+		self.subscriptions_by_index(&SubscriptionIndex::Reference(tips_balance_info.key()))
+	}
+
+	pub fn get_subscription_config(&self) -> SubscriptionConfig {
+		self.subscription_config.clone()
+	}
+}
@@ -0,0 +1,66 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn set_max_tip_records(&mut self, max_tip_records: u64) {
+		self.assert_owner();
+		assert!(max_tip_records > 0, "InvalidMaxTipRecords");
+
+		self.tip_record_config =
+			self.tip_record_config.clone().set_max_tip_records(max_tip_records);
+	}
+
+	/// Removes the oldest tip records above the retention limit. Tipping already prunes a few
+	/// records per tip, this lets anyone catch up after the limit is lowered.
+	pub fn prune_tip_records(&mut self, limit: u64) -> u64 {
+		self.internal_prune_tip_records(limit)
+	}
+
+	pub fn set_cancellation_window(&mut self, window_seconds: u64) {
+		self.assert_owner();
+
+		let cancellation_window = window_seconds.saturating_mul(NANOSECONDS_PER_SECOND);
+
+		self.tip_record_config =
+			self.tip_record_config.clone().set_cancellation_window(cancellation_window);
+	}
+
+	/// Returns a tip to its sender while it is still inside the cancellation window and has not
+	/// been claimed or merged into a claimed reference.
+	#[payable]
+	pub fn cancel_tip(&mut self, tip_id: TipId) {
+		assert_one_yocto();
+
+		let sender = env::predecessor_account_id();
+		let tip_record = self.tip_records.get(&tip_id).expect("TipRecordNotExists");
+
+		assert!(tip_record.get_sender() == &sender, "Unauthorized");
+		assert!(tip_record.is_cancellable(), "TipNotCancellable");
+		assert!(!tip_record.is_cancelled(), "TipAlreadyCancelled");
+
+		let cancellation_window = self.tip_record_config.get_cancellation_window();
+		let cancel_before = tip_record.get_timestamp().saturating_add(cancellation_window);
+
+		assert!(env::block_timestamp() <= cancel_before, "CancellationWindowClosed");
+
+		let key = tip_record.get_key().clone();
+		let tips_balance = self.tips_balances.get(&key).expect("TipsBalanceNotExists");
+
+		self.assert_not_blocked(&sender, tips_balance.get_tips_balance_info());
+		let amount = tip_record.get_amount();
+		let claimed = match tips_balance.get_last_claimed_at() {
+			Some(last_claimed_at) => last_claimed_at >= tip_record.get_timestamp(),
+			None => false,
+		};
+
+		assert!(!claimed, "TipAlreadyClaimed");
+		assert!(tips_balance.get_amount() >= tip_record.get_matched_amount(), "TipAlreadyClaimed");
+
+		let ft_identifier = tip_record.get_ft_identifier().to_string();
+
+		self.assert_not_paused(PauseFeature::Claiming, Some(&ft_identifier));
+		self.apply_tip_cancellation(&tip_record, true);
+		self.transfer(&sender, &ft_identifier, amount)
+			.then(Self::ext(env::current_account_id()).resolve_cancel_tip(tip_id));
+	}
+}
@@ -0,0 +1,230 @@
+use crate::*;
+
+impl Tipping {
+	pub(crate) fn record_tip(
+		&mut self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+		memo: Option<String>,
+		kind: TipKind,
+	) -> TipId {
+		let (tip_record_config, tip_id) = self.tip_record_config.clone().next_tip_id();
+		let tip_record = TipRecord::new(tip_id, sender, tips_balance_info, amount, memo, kind);
+
+		self.tip_record_config = tip_record_config;
+		self.tip_records.insert(&tip_id, &tip_record);
+		self.push_tip_record_index(TipRecordIndex::Reference(tips_balance_info.key()), tip_id);
+		self.push_tip_record_index(TipRecordIndex::Sender(sender.clone()), tip_id);
+		self.internal_prune_tip_records(MAX_PRUNED_PER_TIP);
+
+		tip_id
+	}
+
+	pub(crate) fn update_tip_record(
+		&mut self,
+		tip_id: TipId,
+		update: impl FnOnce(TipRecord) -> TipRecord,
+	) {
+		if let Some(tip_record) = self.tip_records.get(&tip_id) {
+			self.tip_records.insert(&tip_id, &update(tip_record));
+		}
+	}
+
+	pub(crate) fn internal_prune_tip_records(&mut self, limit: u64) -> u64 {
+		let mut tip_record_config = self.tip_record_config.clone();
+		let mut pruned: u64 = 0;
+
+		while pruned < limit &&
+			tip_record_config.total_tip_records() > tip_record_config.get_max_tip_records()
+		{
+			let tip_id = tip_record_config.get_oldest_tip_id();
+
+			if let Some(tip_record) = self.tip_records.remove(&tip_id) {
+				let reference = TipRecordIndex::Reference(tip_record.get_key().clone());
+				let sender = TipRecordIndex::Sender(tip_record.get_sender().clone());
+
+				self.pop_tip_record_index(reference, tip_id);
+				self.pop_tip_record_index(sender, tip_id);
+			}
+
+			tip_record_config = tip_record_config.prune_oldest();
+			pruned += 1;
+		}
+
+		self.tip_record_config = tip_record_config;
+
+		pruned
+	}
+
+	/// Takes a tip out of (or puts it back into) its balance, the sender's contribution, the
+	/// leaderboards, the sender's rate window and the campaign and rounds it counted towards.
+	/// Cancelling also rolls back the matches of the tip, they are not credited again if the
+	/// cancellation fails.
+	pub(crate) fn apply_tip_cancellation(&mut self, tip_record: &TipRecord, cancelled: bool) {
+		let key = tip_record.get_key();
+		let sender = tip_record.get_sender();
+		let amount = tip_record.get_amount();
+
+		self.apply_campaign_cancellation(tip_record, cancelled);
+		self.apply_round_cancellation(tip_record, cancelled);
+
+		if cancelled {
+			self.rollback_tip_matches(tip_record);
+		}
+
+		if let Some(tips_balance) = self.tips_balances.get(key) {
+			let tips_balance_info = tips_balance.get_tips_balance_info().clone();
+			let tips_balance = if cancelled {
+				tips_balance.remove_tip(amount)
+			} else {
+				tips_balance.restore_tip(amount)
+			};
+
+			let rate_limited = tip_record.get_kind().is_rate_limited();
+
+			self.tips_balances.insert(key, &tips_balance);
+
+			if cancelled {
+				self.reduce_supporter_total(key, sender, amount);
+				self.update_top_references(&tips_balance);
+				self.remove_contribution(sender, key, amount);
+
+				if rate_limited {
+					self.release_rate_limit(
+						sender,
+						&tips_balance_info,
+						amount,
+						tip_record.get_timestamp(),
+					);
+				}
+			} else {
+				self.update_leaderboards(sender, &tips_balance, amount);
+
+				if rate_limited {
+					self.record_rate_limit(sender, &tips_balance_info, amount);
+				}
+
+				if tips_balance.get_account_id().is_none() {
+					self.add_contribution(sender, key, amount);
+				}
+			}
+		}
+
+		let tip_id = tip_record.get_id();
+		let tip_record = if cancelled {
+			tip_record.clone().set_cancelled(true).set_matches(Vec::new())
+		} else {
+			tip_record.clone().set_cancelled(false)
+		};
+
+		self.tip_records.insert(&tip_id, &tip_record);
+	}
+
+	pub(crate) fn internal_resolve_cancel_tip(&mut self, tip_id: TipId) {
+		require!(env::promise_results_count() == 1);
+
+		let tip_record = match self.tip_records.get(&tip_id) {
+			Some(tip_record) => tip_record,
+			None => return,
+		};
+
+		match env::promise_result(0) {
+			PromiseResult::Successful(_) => {
+				let sender = tip_record.get_sender();
+				let ft_identifier = tip_record.get_ft_identifier();
+				let amount = tip_record.get_amount();
+				let (symbol, formatted) = self.formatted_amount_of(ft_identifier, amount);
+
+				log!("{} cancelled tip {} of {} {}", sender, tip_id, formatted, symbol);
+			},
+			_ => self.apply_tip_cancellation(&tip_record, false),
+		}
+	}
+
+	pub(crate) fn assert_memo(&self, memo: &Option<String>) {
+		if let Some(memo) = memo {
+			assert!(memo.len() <= MAX_MEMO_LENGTH, "MemoTooLong");
+		}
+	}
+
+	pub(crate) fn tip_records_by_index(
+		&self,
+		index: TipRecordIndex,
+		page_number: Option<u64>,
+		page_limit: Option<u64>,
+	) -> TipRecordWithPagination {
+		let queue = self.tip_record_queues.get(&index).unwrap_or_default();
+
+		if queue.is_empty() {
+			return TipRecordWithPagination::default()
+		}
+
+		let total_item = queue.len();
+		let meta = self.metadata(total_item, page_number, page_limit);
+
+		if meta.current_page.is_none() {
+			return TipRecordWithPagination::default()
+		}
+
+		let page_number = meta.current_page.unwrap();
+		let page_limit = meta.items_per_page;
+		let from_index: u64 = (page_number - 1) * page_limit;
+		let data = (from_index..min(from_index + page_limit, total_item))
+			.filter_map(|index_from_back| {
+				let position = queue.position_from_back(index_from_back);
+				let tip_id = self.tip_record_ids.get(&(index.clone(), position))?;
+				let tip_record = self.tip_records.get(&tip_id)?;
+
+				self.tip_record_with_formatted_amount(tip_record)
+			})
+			.collect();
+
+		TipRecordWithPagination { data, meta }
+	}
+
+	pub(crate) fn tip_record_with_formatted_amount(
+		&self,
+		tip_record: TipRecord,
+	) -> Option<TipRecordWithFormattedAmount> {
+		let ft_identifier = tip_record.get_ft_identifier().to_string();
+		let ft_identifier_info = self.ft_identifiers.get(&ft_identifier)?;
+		let symbol = ft_identifier_info.get_symbol().to_string();
+		let decimals = ft_identifier_info.get_decimals().into();
+		let amount = tip_record.get_amount().to_string();
+		let formatted_amount = self.formatted_balance(&amount, decimals);
+
+		Some(TipRecordWithFormattedAmount { tip_record, symbol, formatted_amount })
+	}
+
+	fn push_tip_record_index(&mut self, index: TipRecordIndex, tip_id: TipId) {
+		let queue = self.tip_record_queues.get(&index).unwrap_or_default();
+		let (queue, position) = queue.push_back();
+
+		self.tip_record_ids.insert(&(index.clone(), position), &tip_id);
+		self.tip_record_queues.insert(&index, &queue);
+	}
+
+	fn pop_tip_record_index(&mut self, index: TipRecordIndex, tip_id: TipId) {
+		let queue = match self.tip_record_queues.get(&index) {
+			Some(queue) if !queue.is_empty() => queue,
+			_ => return,
+		};
+
+		let head = (index.clone(), queue.get_head());
+
+		if self.tip_record_ids.get(&head) != Some(tip_id) {
+			return
+		}
+
+		self.tip_record_ids.remove(&head);
+
+		let queue = queue.pop_front();
+
+		if queue.is_empty() {
+			self.tip_record_queues.remove(&index);
+		} else {
+			self.tip_record_queues.insert(&index, &queue);
+		}
+	}
+}
@@ -0,0 +1,9 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	#[private]
+	pub fn resolve_cancel_tip(&mut self, tip_id: TipId) {
+		self.internal_resolve_cancel_tip(tip_id);
+	}
+}
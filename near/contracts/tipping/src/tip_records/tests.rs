@@ -0,0 +1,304 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tip_records_tests {
+	use crate::*;
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env, RuntimeFeesConfig, VMConfig,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	#[test]
+	fn send_tip_records_tip_with_memo() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+		let tip: Balance = 100000000000000000000000; // 0.1 NEAR
+
+		// Send tips to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(tip).build());
+		contract.send_tip(tips_balance_info.clone(), Some(String::from("great post")));
+
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(tip).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		let by_reference = contract.get_tip_records_by_reference(tips_balance_info, None, None);
+		let by_sender = contract.get_tip_records_by_sender(accounts(3), None, None);
+
+		assert_eq!(by_reference.meta.total_item_count, 2);
+		assert_eq!(by_reference.data[0].tip_record.get_sender(), &accounts(2));
+		assert_eq!(by_reference.data[1].tip_record.get_sender(), &accounts(3));
+		assert_eq!(by_sender.data.len(), 1);
+		assert_eq!(by_sender.data[0].tip_record.get_memo(), &Some(String::from("great post")));
+		assert_eq!(by_sender.data[0].formatted_amount, String::from("0.1"));
+	}
+
+	#[test]
+	fn tip_records_are_pruned_oldest_first() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.set_max_tip_records(2);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send tips to user-user_id
+		for _ in 0..3 {
+			testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+			contract.send_tip(tips_balance_info.clone(), None);
+		}
+
+		// Test
+		let by_reference = contract.get_tip_records_by_reference(tips_balance_info, None, None);
+		let by_sender = contract.get_tip_records_by_sender(accounts(3), None, None);
+
+		assert!(contract.get_tip_record(0).is_none());
+		assert_eq!(by_reference.meta.total_item_count, 2);
+		assert_eq!(by_reference.data[0].tip_record.get_id(), 2);
+		assert_eq!(by_reference.data[1].tip_record.get_id(), 1);
+		assert_eq!(by_sender.meta.total_item_count, 2);
+	}
+
+	#[test]
+	#[should_panic(expected = "MemoTooLong")]
+	fn cant_send_tip_when_memo_too_long() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+		let memo = "a".repeat(MAX_MEMO_LENGTH + 1);
+
+		// Send tip to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+		contract.send_tip(tips_balance_info, Some(memo));
+	}
+
+	#[test]
+	fn cancel_tip_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send tips to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		let tip_id = contract.send_tip(tips_balance_info.clone(), None);
+
+		// Cancel the second tip
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.block_timestamp(DEFAULT_CANCELLATION_WINDOW)
+			.attached_deposit(1)
+			.build());
+		contract.cancel_tip(tip_id);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info.clone()).unwrap();
+		let supporter = contract.get_supporter_total(tips_balance_info, accounts(3)).unwrap();
+
+		assert_eq!(tips_balance.tips_balance.get_amount(), 10);
+		assert_eq!(tips_balance.tips_balance.get_tip_count(), 1);
+		assert_eq!(supporter.supporter.get_amount(), 10);
+		assert!(contract.get_tip_record(tip_id).unwrap().tip_record.is_cancelled());
+	}
+
+	#[test]
+	#[should_panic(expected = "CancellationWindowClosed")]
+	fn cant_cancel_tip_after_window() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send tip to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+		let tip_id = contract.send_tip(tips_balance_info, None);
+
+		// Cancel the tip too late
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.block_timestamp(DEFAULT_CANCELLATION_WINDOW + 1)
+			.attached_deposit(1)
+			.build());
+		contract.cancel_tip(tip_id);
+	}
+
+	#[test]
+	#[should_panic(expected = "Unauthorized")]
+	fn cant_cancel_tip_of_other_sender() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send tip to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+		let tip_id = contract.send_tip(tips_balance_info, None);
+
+		// Cancel the tip as another account
+		testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipAlreadyClaimed")]
+	fn cant_cancel_tip_while_claim_in_flight() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Reference is claimed for accounts(2)
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+		let tips_balance = TipsBalance::new(&tips_balance_info).set_account_id(&accounts(2));
+
+		contract.tips_balances.insert(&tips_balance_info.key(), &tips_balance);
+
+		// Send tip to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+		let tip_id = contract.send_tip(tips_balance_info.clone(), None);
+
+		// accounts(2) claims, the transfer is still in flight
+		testing_env!(context
+			.signer_account_id(accounts(2))
+			.predecessor_account_id(accounts(2))
+			.attached_deposit(1)
+			.build());
+		contract.claim_tip(tips_balance_info);
+
+		// Cancel the claimed tip
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(1)
+			.build());
+		contract.cancel_tip(tip_id);
+	}
+
+	#[test]
+	fn cancel_tip_while_claim_reference_in_flight() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let user = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+		let post = TipsBalanceInfo::new(&accounts(4), "post", "post_id", "native");
+
+		// Send tips to user-user_id and post-post_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.send_tip(user.clone(), None);
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(50).build());
+		let tip_id = contract.send_tip(post.clone(), None);
+
+		// Server claims post-post_id for user-user_id, the tx fee is still in flight
+		testing_env!(context
+			.signer_account_id(accounts(4))
+			.predecessor_account_id(accounts(4))
+			.attached_deposit(1)
+			.build());
+		contract.claim_reference(
+			post.clone(),
+			String::from("user"),
+			String::from("user_id"),
+			accounts(2),
+			String::from("10"),
+		);
+
+		// Cancel the tip of post-post_id
+		testing_env!(context
+			.signer_account_id(accounts(3))
+			.predecessor_account_id(accounts(3))
+			.attached_deposit(1)
+			.build());
+		contract.cancel_tip(tip_id);
+
+		// Resolve the claim
+		testing_env!(
+			context
+				.signer_account_id(accounts(4))
+				.predecessor_account_id(accounts(0))
+				.build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![PromiseResult::Successful(vec![])],
+		);
+		contract.resolve_claim_reference(
+			post.clone(),
+			String::from("user"),
+			String::from("user_id"),
+			accounts(2),
+			U128(10),
+		);
+
+		// Test
+		let user_balance = contract.get_tips_balance(user).unwrap().tips_balance;
+		let post_balance = contract.get_tips_balance(post).unwrap().tips_balance;
+
+		assert_eq!(user_balance.get_amount(), 90);
+		assert_eq!(user_balance.get_account_id(), &Some(accounts(2)));
+		assert_eq!(post_balance.get_amount(), 0);
+	}
+
+	#[test]
+	fn cancel_tip_gives_back_rate_usage() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.set_rate_limit(accounts(4), String::from("native"), 60, Some(1), None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send tip to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+		let tip_id = contract.send_tip(tips_balance_info.clone(), None);
+
+		// Cancel the tip
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_id);
+
+		// Test
+		let rate_usage = contract.get_rate_usage(accounts(3), tips_balance_info.clone());
+
+		assert_eq!(rate_usage.tips, 0);
+		assert_eq!(rate_usage.volume, U128(0));
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(10).build());
+		contract.send_tip(tips_balance_info, None);
+	}
+}
@@ -0,0 +1,282 @@
+use crate::*;
+
+pub type TipId = u64;
+
+pub const MAX_MEMO_LENGTH: usize = 256;
+pub const DEFAULT_MAX_TIP_RECORDS: u64 = 10_000;
+pub const MAX_PRUNED_PER_TIP: u64 = 2;
+pub const DEFAULT_CANCELLATION_WINDOW: u64 = 5 * 60 * NANOSECONDS_PER_SECOND;
+
+/// How a tip entered its balance. Only tips sent directly by their sender can be cancelled.
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum TipKind {
+	/// Sent by the sender, who can take it back within the cancellation window.
+	Direct,
+	/// Paid out of the sender's deposit or escrow by someone else, like a voucher, a subscription
+	/// payment or a released conditional tip. Final once settled.
+	Settled,
+	/// Credited by the contract out of funds it already holds, like a stream settlement, a match
+	/// or a bounty award. Final.
+	Credit,
+}
+impl TipKind {
+	/// Whether the tip was initiated by its sender and counts towards the sender's rate limit.
+	pub fn is_rate_limited(&self) -> bool {
+		*self != TipKind::Credit
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TipRecord {
+	id: TipId,
+	sender: AccountId,
+	key: TipsBalanceKey,
+	amount: Balance,
+	ft_identifier: FtIdentifier,
+	memo: Option<String>,
+	timestamp: Timestamp,
+	kind: TipKind,
+	cancelled: bool,
+	#[serde(skip)]
+	campaign_round: Option<u64>,
+	#[serde(skip)]
+	matches: Vec<TipMatch>,
+	#[serde(skip)]
+	funding_round_ids: Vec<FundingRoundId>,
+}
+impl TipRecord {
+	pub fn new(
+		id: TipId,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+		memo: Option<String>,
+		kind: TipKind,
+	) -> Self {
+		Self {
+			id,
+			sender: sender.clone(),
+			key: tips_balance_info.key(),
+			amount,
+			ft_identifier: tips_balance_info.get_ft_identifier().to_string(),
+			memo,
+			timestamp: env::block_timestamp(),
+			kind,
+			cancelled: false,
+			campaign_round: None,
+			matches: Vec::new(),
+			funding_round_ids: Vec::new(),
+		}
+	}
+
+	pub fn get_id(&self) -> TipId {
+		self.id
+	}
+
+	pub fn get_sender(&self) -> &AccountId {
+		&self.sender
+	}
+
+	pub fn get_key(&self) -> &TipsBalanceKey {
+		&self.key
+	}
+
+	pub fn get_amount(&self) -> Balance {
+		self.amount
+	}
+
+	pub fn get_ft_identifier(&self) -> &str {
+		&self.ft_identifier
+	}
+
+	pub fn get_memo(&self) -> &Option<String> {
+		&self.memo
+	}
+
+	pub fn get_timestamp(&self) -> Timestamp {
+		self.timestamp
+	}
+
+	pub fn get_kind(&self) -> TipKind {
+		self.kind
+	}
+
+	pub fn is_cancellable(&self) -> bool {
+		self.kind == TipKind::Direct
+	}
+
+	pub fn is_cancelled(&self) -> bool {
+		self.cancelled
+	}
+
+	/// Round of the campaign the tip counted towards, if any.
+	pub fn get_campaign_round(&self) -> Option<u64> {
+		self.campaign_round
+	}
+
+	pub fn get_matches(&self) -> &Vec<TipMatch> {
+		&self.matches
+	}
+
+	pub fn get_funding_round_ids(&self) -> &Vec<FundingRoundId> {
+		&self.funding_round_ids
+	}
+
+	/// Total of the tip and the matches credited for it.
+	pub fn get_matched_amount(&self) -> Balance {
+		self.matches
+			.iter()
+			.fold(self.amount, |total, tip_match| total + tip_match.amount)
+	}
+
+	pub fn set_cancelled(mut self, cancelled: bool) -> Self {
+		self.cancelled = cancelled;
+		self
+	}
+
+	pub fn set_campaign_round(mut self, campaign_round: u64) -> Self {
+		self.campaign_round = Some(campaign_round);
+		self
+	}
+
+	pub fn set_matches(mut self, matches: Vec<TipMatch>) -> Self {
+		self.matches = matches;
+		self
+	}
+
+	pub fn set_funding_round_ids(mut self, funding_round_ids: Vec<FundingRoundId>) -> Self {
+		self.funding_round_ids = funding_round_ids;
+		self
+	}
+}
+
+/// A tip record index is an append-only queue of tip ids, one per reference and one per sender.
+/// Records are pruned oldest first, so the oldest record is always at the head of its queues.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, Eq)]
+pub enum TipRecordIndex {
+	Reference(TipsBalanceKey),
+	Sender(AccountId),
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct TipRecordQueue {
+	head: u64,
+	tail: u64,
+}
+impl TipRecordQueue {
+	pub fn len(&self) -> u64 {
+		self.tail - self.head
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	pub fn get_head(&self) -> u64 {
+		self.head
+	}
+
+	/// Position of the n-th newest entry.
+	pub fn position_from_back(&self, index: u64) -> u64 {
+		self.tail - 1 - index
+	}
+
+	pub fn push_back(mut self) -> (Self, u64) {
+		let position = self.tail;
+		self.tail += 1;
+		(self, position)
+	}
+
+	pub fn pop_front(mut self) -> Self {
+		self.head += 1;
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TipRecordConfig {
+	next_tip_id: TipId,
+	oldest_tip_id: TipId,
+	max_tip_records: u64,
+	cancellation_window: u64,
+}
+impl Default for TipRecordConfig {
+	fn default() -> Self {
+		Self {
+			next_tip_id: 0,
+			oldest_tip_id: 0,
+			max_tip_records: DEFAULT_MAX_TIP_RECORDS,
+			cancellation_window: DEFAULT_CANCELLATION_WINDOW,
+		}
+	}
+}
+impl TipRecordConfig {
+	pub fn get_next_tip_id(&self) -> TipId {
+		self.next_tip_id
+	}
+
+	pub fn get_oldest_tip_id(&self) -> TipId {
+		self.oldest_tip_id
+	}
+
+	pub fn get_max_tip_records(&self) -> u64 {
+		self.max_tip_records
+	}
+
+	pub fn get_cancellation_window(&self) -> u64 {
+		self.cancellation_window
+	}
+
+	pub fn total_tip_records(&self) -> u64 {
+		self.next_tip_id - self.oldest_tip_id
+	}
+
+	pub fn next_tip_id(mut self) -> (Self, TipId) {
+		let tip_id = self.next_tip_id;
+		self.next_tip_id += 1;
+		(self, tip_id)
+	}
+
+	pub fn prune_oldest(mut self) -> Self {
+		self.oldest_tip_id += 1;
+		self
+	}
+
+	pub fn set_max_tip_records(mut self, max_tip_records: u64) -> Self {
+		self.max_tip_records = max_tip_records;
+		self
+	}
+
+	pub fn set_cancellation_window(mut self, cancellation_window: u64) -> Self {
+		self.cancellation_window = cancellation_window;
+		self
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TipRecordWithFormattedAmount {
+	pub tip_record: TipRecord,
+	pub symbol: String,
+	pub formatted_amount: String,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TipRecordWithPagination {
+	pub data: Vec<TipRecordWithFormattedAmount>,
+	pub meta: Metadata,
+}
+impl Default for TipRecordWithPagination {
+	fn default() -> Self {
+		let data = Vec::new();
+		let meta = Metadata::default();
+
+		Self { data, meta }
+	}
+}
@@ -0,0 +1,36 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_tip_record(&self, tip_id: TipId) -> Option<TipRecordWithFormattedAmount> {
+		let tip_record = self.tip_records.get(&tip_id)?;
+
+		self.tip_record_with_formatted_amount(tip_record)
+	}
+
+	pub fn get_tip_records_by_reference(
+		&self,
+		tips_balance_info: TipsBalanceInfo,
+		page_number: Option<u64>,
+		page_limit: Option<u64>,
+	) -> TipRecordWithPagination {
+		let index = TipRecordIndex::Reference(tips_balance_info.key());
+
+		self.tip_records_by_index(index, page_number, page_limit)
+	}
+
+	pub fn get_tip_records_by_sender(
+		&self,
+		sender: AccountId,
+		page_number: Option<u64>,
+		page_limit: Option<u64>,
+	) -> TipRecordWithPagination {
+		let index = TipRecordIndex::Sender(sender);
+
+		self.tip_records_by_index(index, page_number, page_limit)
+	}
+
+	pub fn get_tip_record_config(&self) -> TipRecordConfig {
+		self.tip_record_config.clone()
+	}
+}
@@ -3,42 +3,111 @@ use crate::*;
 #[near_bindgen]
 impl Tipping {
 	#[payable]
-	pub fn send_tip(&mut self, tips_balance_info: TipsBalanceInfo) {
+	pub fn send_tip(&mut self, tips_balance_info: TipsBalanceInfo, memo: Option<String>) -> TipId {
 		let tip_amount = env::attached_deposit();
 		let account_balance = env::account_balance();
 		let ft_identifier = tips_balance_info.get_ft_identifier();
 
 		assert!(ft_identifier == "native", "OnlyForNear");
 		assert!(account_balance > tip_amount, "InsufficientBalance");
-		assert!(tip_amount > 0, "TipsMustMoreThanMinimum");
 
 		let sender = env::predecessor_account_id();
+
+		self.assert_tip_allowed(&sender, &tips_balance_info, tip_amount);
+		self.assert_memo(&memo);
 		let reference = tips_balance_info.reference();
 		let amount_str = tip_amount.to_string();
 
-		self.create_tips_balance(&tips_balance_info, &tip_amount);
+		let tip_id = self.create_tips_balance(
+			&sender,
+			&tips_balance_info,
+			&tip_amount,
+			memo,
+			TipKind::Direct,
+		);
 		self.tip_log(&sender, &amount_str, "NEAR", 24, Some(reference));
+		self.add_round_contribution(&sender, &tips_balance_info, tip_amount, tip_id);
+		self.match_tip(&sender, &tips_balance_info, tip_amount, tip_id);
+
+		tip_id
+	}
+
+	/// Tips several references in NEAR at once, the attached deposit must equal the sum of the
+	/// amounts.
+	#[payable]
+	pub fn send_tips(&mut self, tips: Vec<BatchTip>, memo: Option<String>) -> Vec<TipId> {
+		let deposit = env::attached_deposit();
+		let account_balance = env::account_balance();
+		let sender = env::predecessor_account_id();
+
+		self.assert_batch_tips(&tips, deposit);
+		self.assert_memo(&memo);
+		assert!(account_balance > deposit, "InsufficientBalance");
+
+		tips.into_iter()
+			.map(|(tips_balance_info, amount)| {
+				let amount = amount.0;
+				let ft_identifier = tips_balance_info.get_ft_identifier();
+
+				assert!(ft_identifier == "native", "OnlyForNear");
+				self.assert_tip_allowed(&sender, &tips_balance_info, amount);
+
+				let reference = tips_balance_info.reference();
+				let amount_str = amount.to_string();
+				let tip_id = self.create_tips_balance(
+					&sender,
+					&tips_balance_info,
+					&amount,
+					memo.clone(),
+					TipKind::Direct,
+				);
+
+				self.tip_log(&sender, &amount_str, "NEAR", 24, Some(reference));
+				self.add_round_contribution(&sender, &tips_balance_info, amount, tip_id);
+				self.match_tip(&sender, &tips_balance_info, amount, tip_id);
+
+				tip_id
+			})
+			.collect()
+	}
+
+	/// Splits the attached deposit across several references by their weights in basis points,
+	/// which must add up to 10000.
+	#[payable]
+	pub fn send_split_tip(&mut self, shares: Vec<SplitShare>, memo: Option<String>) -> Vec<TipId> {
+		let tips = self.split_tips(&shares, env::attached_deposit());
+
+		self.send_tips(tips, memo)
 	}
 
 	#[payable]
 	pub fn claim_tip(&mut self, tips_balance_info: TipsBalanceInfo) {
 		assert_one_yocto();
 
+		let ft_id = tips_balance_info.get_ft_identifier();
+
+		self.assert_not_paused(PauseFeature::Claiming, Some(ft_id));
+		self.assert_reference_not_blocked(&tips_balance_info);
+
 		let tips_balance_key = tips_balance_info.key();
+
+		self.settle_streams(&tips_balance_key);
+
 		let tips_balance = self.tips_balances.get(&tips_balance_key).expect("TipsBalanceNotExists");
-		let amount = tips_balance.get_amount();
+		let locked_amount = self.campaign_locked_amount(&tips_balance_key);
+		let amount = tips_balance.get_amount().saturating_sub(locked_amount);
 		let receiver = env::signer_account_id();
 		let account_id = tips_balance.get_account_id().as_ref().expect("ReceiverNotExists");
 
 		assert!(amount > 0, "NothingToClaimed");
 		assert!(account_id == &receiver, "Unauthorized");
 
-		let ft_id = tips_balance_info.get_ft_identifier();
-		let tips_balance = tips_balance.set_balance(Zero::zero());
-		let claim_tips_promise = self.transfer(&receiver, ft_id, amount);
+		let tips_balance = self.debit_claimed_amount(tips_balance, amount);
+		let payouts = self.revenue_payouts(&tips_balance, &receiver, amount);
+		let claim_tips_promise = self.transfer_payouts(ft_id, &payouts);
 
 		claim_tips_promise
-			.then(Self::ext(env::current_account_id()).resolve_claim_tip(tips_balance, amount));
+			.then(Self::ext(env::current_account_id()).resolve_claim_tip(tips_balance, payouts));
 	}
 
 	#[payable]
@@ -49,19 +118,47 @@ impl Tipping {
 		reference_id: ReferenceId,
 	) {
 		assert_one_yocto();
+		self.assert_not_paused(PauseFeature::Claiming, None);
 
 		let receiver = env::signer_account_id();
+		let tips_balance_info =
+			TipsBalanceInfo::new(&server_id, &reference_type, &reference_id, "native");
+
+		self.assert_reference_not_blocked(&tips_balance_info);
+
+		let keys: Vec<TipsBalanceKey> = self
+			.ft_identifiers
+			.keys()
+			.map(|ft_identifier| {
+				TipsBalanceKey::new(&server_id, &reference_type, &reference_id, &ft_identifier)
+			})
+			.collect();
+
+		for key in keys.iter() {
+			self.settle_streams(key);
+		}
+
 		let total_ft = self.ft_identifiers.len();
 		let values = self.ft_identifiers.values_as_vector();
+
 		let tips_balances: Vec<TipsBalance> = (0..total_ft)
 			.filter_map(|index| {
 				let ft_info = values.get(index).unwrap();
 				let ft_identifier = ft_info.get_ft_identifier();
+
+				if self.is_paused(PauseFeature::Claiming, Some(ft_identifier)) {
+					return None
+				}
+
 				let key =
 					TipsBalanceKey::new(&server_id, &reference_type, &reference_id, ft_identifier);
 				let tips_balance = self.tips_balances.get(&key);
 
 				if let Some(tips_balance) = tips_balance {
+					let locked_amount = self.campaign_locked_amount(&key);
+					let amount = tips_balance.get_amount().saturating_sub(locked_amount);
+					let tips_balance = tips_balance.set_balance(amount);
+
 					if tips_balance.get_amount().is_zero() {
 						return None
 					}
@@ -83,22 +180,33 @@ impl Tipping {
 
 		assert!(!tips_balances.is_empty(), "NothingToClaimed");
 
-		let init_tips_balance = &tips_balances[0];
-		let init_ft = init_tips_balance.get_ft_identifier();
-		let init_amount = init_tips_balance.get_amount();
-		let receiver = env::signer_account_id();
-		let mut tips_promise = self.transfer(&receiver, init_ft, init_amount);
+		let payouts: Vec<Vec<Payout>> = tips_balances
+			.iter()
+			.map(|tips_balance| {
+				self.revenue_payouts(tips_balance, &receiver, tips_balance.get_amount())
+			})
+			.collect();
+		let mut tips_promise: Option<Promise> = None;
+
+		for tips_balance in tips_balances.iter() {
+			let stored = self.tips_balances.get(&tips_balance.key()).expect("TipsBalanceNotExists");
 
-		for tips_balance in tips_balances.iter().skip(1) {
+			self.debit_claimed_amount(stored, tips_balance.get_amount());
+		}
+
+		for (tips_balance, payouts) in tips_balances.iter().zip(payouts.iter()) {
 			let ft = tips_balance.get_ft_identifier();
-			let tips_amount = tips_balance.get_amount();
-			let receiver = tips_balance.get_account_id().as_ref().unwrap();
+			let promise = self.transfer_payouts(ft, payouts);
 
-			tips_promise = tips_promise.and(self.transfer(receiver, ft, tips_amount));
+			tips_promise = match tips_promise {
+				Some(tips_promise) => Some(tips_promise.and(promise)),
+				None => Some(promise),
+			};
 		}
 
-		tips_promise
-			.then(Self::ext(env::current_account_id()).resolve_batch_claim_tip(tips_balances));
+		tips_promise.unwrap().then(
+			Self::ext(env::current_account_id()).resolve_batch_claim_tip(tips_balances, payouts),
+		);
 	}
 
 	#[payable]
@@ -115,6 +223,8 @@ impl Tipping {
 
 		assert_one_yocto();
 		assert!(receiver != account_id, "Unauthorized");
+		self.assert_not_paused(PauseFeature::Referencing, None);
+		self.assert_reference_not_blocked(&tips_balance_info);
 
 		// Check near balance for tx fee
 		let native_key = TipsBalanceKey::new(&receiver, &reference_type, &reference_id, "native");
@@ -129,24 +239,17 @@ impl Tipping {
 		assert!(tx_fee > 0, "InsufficientTxFee");
 		assert!(total_tip >= tx_fee, "InsufficientTxFee");
 
-		// Calculate tips
-		let main_balance = self.calculate_tips(&tips_balance_info, &reference_type, &reference_id);
-		let main_balance_key = main_balance.key();
-
-		let mut balance = main_balance.get_amount();
-
-		let native_tips_balance: Option<TipsBalance> = if main_balance_key != native_key {
-			Some(native_tips_balance.set_balance(total_tip - tx_fee).set_account_id(&account_id))
-		} else {
-			balance -= tx_fee;
-			None
-		};
+		// Take the tx fee out before it is paid, the tips are merged once it is
+		self.tips_balances
+			.insert(&native_key, &native_tips_balance.set_balance(total_tip - tx_fee));
 
 		Promise::new(receiver).transfer(tx_fee).then(
 			Self::ext(env::current_account_id()).resolve_claim_reference(
-				tips_balance_info.key(),
-				main_balance.set_account_id(&account_id).set_balance(balance),
-				native_tips_balance,
+				tips_balance_info,
+				reference_type,
+				reference_id,
+				account_id,
+				U128(tx_fee),
 			),
 		);
 	}
@@ -165,6 +268,14 @@ impl Tipping {
 
 		assert_one_yocto();
 		assert!(receiver != account_id, "Unauthorized");
+		self.assert_not_paused(PauseFeature::Referencing, None);
+
+		for reference_id in reference_ids.iter() {
+			let tips_balance_info =
+				TipsBalanceInfo::new(&receiver, &reference_type, reference_id, "native");
+
+			self.assert_reference_not_blocked(&tips_balance_info);
+		}
 
 		// Check near balance
 		let native_key = TipsBalanceKey::new(&receiver, &main_ref_type, &main_ref_id, "native");
@@ -179,18 +290,38 @@ impl Tipping {
 		assert!(tx_fee > 0, "InsufficientTxFee");
 		assert!(total_tip >= tx_fee, "InsufficientTxFee");
 
-		let (main_tip_balances, keys) = self.batch_calculate_tips(
-			&reference_type,
-			&reference_ids,
-			&main_ref_type,
-			&main_ref_id,
-			&account_id,
-			tx_fee,
-		);
+		// Take the tx fee out before it is paid, the tips are merged once it is
+		self.tips_balances
+			.insert(&native_key, &native_tips.set_balance(total_tip - tx_fee));
 
 		Promise::new(receiver).transfer(tx_fee).then(
-			Self::ext(env::current_account_id())
-				.resolve_batch_claim_reference(keys, main_tip_balances),
+			Self::ext(env::current_account_id()).resolve_batch_claim_reference(
+				reference_type,
+				reference_ids,
+				main_ref_type,
+				main_ref_id,
+				account_id,
+				U128(tx_fee),
+			),
 		);
 	}
+
+	pub fn set_tip_limit(
+		&mut self,
+		ft_identifier: FtIdentifier,
+		min_amount: Option<U128>,
+		max_amount: Option<U128>,
+	) {
+		self.assert_owner();
+
+		if let (Some(min_amount), Some(max_amount)) = (min_amount, max_amount) {
+			assert!(min_amount.0 <= max_amount.0, "InvalidTipLimit");
+		}
+
+		if min_amount.is_none() && max_amount.is_none() {
+			self.tip_limits.remove(&ft_identifier);
+		} else {
+			self.tip_limits.insert(&ft_identifier, &TipLimit::new(min_amount, max_amount));
+		}
+	}
 }
@@ -19,22 +19,116 @@ impl Tipping {
 		}
 	}
 
+	pub(crate) fn tip_limit_reason(
+		&self,
+		ft_identifier: &str,
+		amount: Balance,
+	) -> Option<&'static str> {
+		let tip_limit = self.tip_limits.get(&ft_identifier.to_string()).unwrap_or_default();
+
+		tip_limit.check(amount)
+	}
+
+	/// Returns the reason a tip cannot be accepted, if it cannot.
+	pub(crate) fn tip_rejection_reason(
+		&self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+	) -> Option<&'static str> {
+		let ft_identifier = tips_balance_info.get_ft_identifier();
+
+		self.paused_reason(PauseFeature::Tipping, Some(ft_identifier))
+			.or_else(|| self.blocked_reason(sender, tips_balance_info))
+			.or_else(|| self.tip_limit_reason(ft_identifier, amount))
+			.or_else(|| self.rate_limit_reason(sender, tips_balance_info, amount))
+	}
+
+	pub(crate) fn assert_tip_allowed(
+		&self,
+		sender: &AccountId,
+		tips_balance_info: &TipsBalanceInfo,
+		amount: Balance,
+	) {
+		if let Some(reason) = self.tip_rejection_reason(sender, tips_balance_info, amount) {
+			panic!("{}", reason)
+		}
+	}
+
+	pub(crate) fn assert_batch_tips(&self, tips: &[BatchTip], amount: Balance) {
+		assert!(!tips.is_empty(), "NothingToTip");
+		assert!(tips.len() <= MAX_BATCH_TIPS, "TooManyTips");
+
+		let total = tips.iter().try_fold(0u128, |total, (_, amount)| total.checked_add(amount.0));
+
+		assert!(total == Some(amount), "InvalidTipsAmount");
+	}
+
+	/// Splits an amount by the weights of the shares. The rounding dust goes to the first share and
+	/// shares that round down to nothing are left out.
+	pub(crate) fn split_tips(&self, shares: &[SplitShare], amount: Balance) -> Vec<BatchTip> {
+		assert!(!shares.is_empty(), "NothingToTip");
+		assert!(shares.len() <= MAX_BATCH_TIPS, "TooManyTips");
+		assert!(shares.iter().all(|(_, weight)| *weight > 0), "InvalidSplitWeight");
+
+		let total_weight: u32 = shares.iter().map(|(_, weight)| *weight as u32).sum();
+
+		assert!(total_weight == TOTAL_SPLIT_WEIGHT as u32, "InvalidSplitWeight");
+
+		let mut amounts: Vec<Balance> = shares
+			.iter()
+			.map(|(_, weight)| {
+				self.proportion(amount, *weight as Balance, TOTAL_SPLIT_WEIGHT as Balance)
+			})
+			.collect();
+		let dust = amount - amounts.iter().sum::<Balance>();
+
+		amounts[0] += dust;
+
+		shares
+			.iter()
+			.zip(amounts)
+			.filter(|(_, amount)| !amount.is_zero())
+			.map(|((tips_balance_info, _), amount)| (tips_balance_info.clone(), U128(amount)))
+			.collect()
+	}
+
 	pub(crate) fn create_tips_balance(
 		&mut self,
+		sender: &AccountId,
 		tips_balance_info: &TipsBalanceInfo,
 		amount: &Balance,
-	) -> TipsBalance {
+		memo: Option<String>,
+		kind: TipKind,
+	) -> TipId {
 		let tips_balance_info = tips_balance_info.clone();
 		let tips_balance_key = tips_balance_info.key();
 
 		let tips_balance = match self.tips_balances.get(&tips_balance_key) {
-			Some(tips_balance) => tips_balance.add_balance(*amount),
-			None => TipsBalance::new(&tips_balance_info).set_balance(*amount),
+			Some(tips_balance) => tips_balance.add_tip(*amount),
+			None => TipsBalance::new(&tips_balance_info).add_tip(*amount),
 		};
 
 		self.tips_balances.insert(&tips_balance_key, &tips_balance);
+		self.update_leaderboards(sender, &tips_balance, *amount);
 
-		tips_balance
+		if kind.is_rate_limited() {
+			self.record_rate_limit(sender, &tips_balance_info, *amount);
+		}
+
+		if tips_balance.get_account_id().is_none() {
+			self.add_contribution(sender, &tips_balance_key, *amount);
+		}
+
+		let tip_id = self.record_tip(sender, &tips_balance_info, *amount, memo, kind);
+
+		if let Some(round) = self.add_campaign_contribution(sender, &tips_balance_key, *amount) {
+			self.update_tip_record(tip_id, |tip_record| tip_record.set_campaign_round(round));
+		}
+
+		self.forward_tip(tips_balance, *amount);
+
+		tip_id
 	}
 
 	pub(crate) fn claim_tips_balance(
@@ -42,51 +136,93 @@ impl Tipping {
 		secondary_key: &TipsBalanceKey,
 		main_balance: &TipsBalance,
 	) -> TipsBalance {
+		let key = main_balance.key();
 		let other_tips_balance = self.tips_balances.get(secondary_key);
+		let mut main_balance = main_balance.clone();
 
 		if let Some(tips_balance) = other_tips_balance {
 			if !tips_balance.get_amount().is_zero() {
-				let tips_balance = tips_balance.set_balance(Zero::zero());
+				if secondary_key != &key {
+					main_balance = main_balance.merge_stats(&tips_balance);
+				}
+
+				let tips_balance = tips_balance.sweep();
+
 				self.tips_balances.insert(secondary_key, &tips_balance);
+				self.update_top_references(&tips_balance);
+				self.reset_contributions(secondary_key);
 			}
 		}
 
-		let key = main_balance.key();
 		let reference_type = main_balance.get_reference_type();
 		let reference_id = main_balance.get_reference_id();
 
-		self.tips_balances.insert(&key, main_balance);
+		self.tips_balances.insert(&key, &main_balance);
+		self.update_top_references(&main_balance);
+		self.reset_contributions(&key);
 		self.claim_reference_log(reference_type, reference_id);
 
-		main_balance.clone()
+		main_balance
 	}
 
 	pub(crate) fn batch_claim_tips_balance(
 		&mut self,
 		secondary_keys: &Vec<TipsBalanceKey>,
-		main_balances: &Vec<TipsBalance>,
+		main_balances: &[TipsBalance],
 	) {
+		let mut main_balances = main_balances.to_vec();
+
 		for secondary_key in secondary_keys {
 			let other_tips_balance = self.tips_balances.get(secondary_key);
 
 			if let Some(tips_balance) = other_tips_balance {
 				if !tips_balance.get_amount().is_zero() {
-					let tips_balance = tips_balance.set_balance(Zero::zero());
+					let ft_identifier = tips_balance.get_ft_identifier();
+					let main_balance = main_balances.iter_mut().find(|main_balance| {
+						main_balance.get_ft_identifier() == ft_identifier &&
+							&main_balance.key() != secondary_key
+					});
+
+					if let Some(main_balance) = main_balance {
+						*main_balance = main_balance.clone().merge_stats(&tips_balance);
+					}
+
+					let tips_balance = tips_balance.sweep();
+
 					self.tips_balances.insert(secondary_key, &tips_balance);
+					self.update_top_references(&tips_balance);
+					self.reset_contributions(secondary_key);
 				}
 			}
 		}
 
-		for main_balance in main_balances {
+		for main_balance in main_balances.iter() {
 			let key = main_balance.key();
 			let reference_type = main_balance.get_reference_type();
 			let reference_id = main_balance.get_reference_id();
 
 			self.tips_balances.insert(&key, main_balance);
+			self.update_top_references(main_balance);
+			self.reset_contributions(&key);
 			self.claim_reference_log(reference_type, reference_id);
 		}
 	}
 
+	/// Takes a claimed amount out of the stored balance before it is paid out, so the same tips
+	/// can't be cancelled or claimed again while the transfers are in flight.
+	pub(crate) fn debit_claimed_amount(
+		&mut self,
+		tips_balance: TipsBalance,
+		amount: Balance,
+	) -> TipsBalance {
+		let remaining = tips_balance.get_amount().checked_sub(amount).expect("NothingToClaimed");
+		let tips_balance = tips_balance.set_balance(remaining).set_claimed();
+
+		self.tips_balances.insert(&tips_balance.key(), &tips_balance);
+
+		tips_balance
+	}
+
 	pub(crate) fn calculate_tips(
 		&mut self,
 		tips_balance_info: &TipsBalanceInfo,
@@ -117,7 +253,6 @@ impl Tipping {
 		main_ref_type: &ReferenceType,
 		main_ref_id: &ReferenceId,
 		account_id: &AccountId,
-		tx_fee: Balance,
 	) -> (Vec<TipsBalance>, Vec<TipsBalanceKey>) {
 		let server_id = env::signer_account_id();
 		let values = self.ft_identifiers.values_as_vector();
@@ -151,13 +286,6 @@ impl Tipping {
 				},
 			};
 
-			let main_tips_balance = if ft_id == "native" {
-				let amount = main_tips_balance.get_amount();
-				main_tips_balance.set_balance(amount - tx_fee)
-			} else {
-				main_tips_balance
-			};
-
 			tips_balances.push(main_tips_balance.set_account_id(account_id));
 		}
 
@@ -169,9 +297,15 @@ impl Tipping {
 		sender: AccountId,
 		tips_balance_info: TipsBalanceInfo,
 		amount: Balance,
+		memo: Option<String>,
 	) -> U128 {
 		require!(env::promise_results_count() == 1);
 
+		if let Some(reason) = self.tip_rejection_reason(&sender, &tips_balance_info, amount) {
+			log!("{}: refunding {} to {}", reason, amount, sender);
+			return U128(amount)
+		}
+
 		if let PromiseResult::Successful(val) = env::promise_result(0) {
 			if let Ok(ft_metadata) = from_slice::<FungibleTokenMetadata>(&val) {
 				let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
@@ -181,8 +315,16 @@ impl Tipping {
 				let reference = tips_balance_info.reference();
 
 				self.create_ft_identifier(&ft_identifier, symbol, &(decimals as u8));
-				self.create_tips_balance(&tips_balance_info, &amount);
+				let tip_id = self.create_tips_balance(
+					&sender,
+					&tips_balance_info,
+					&amount,
+					memo,
+					TipKind::Direct,
+				);
 				self.tip_log(&sender, &amount_str, symbol, decimals, Some(reference));
+				self.add_round_contribution(&sender, &tips_balance_info, amount, tip_id);
+				self.match_tip(&sender, &tips_balance_info, amount, tip_id);
 
 				return U128(0)
 			}
@@ -191,85 +333,201 @@ impl Tipping {
 		U128(amount)
 	}
 
+	/// Credits every valid tip of a batch and returns the amount to refund for the invalid ones.
+	pub(crate) fn internal_resolve_send_tips(
+		&mut self,
+		sender: AccountId,
+		ft_identifier: FtIdentifier,
+		tips: Vec<BatchTip>,
+		memo: Option<String>,
+	) -> U128 {
+		require!(env::promise_results_count() == 1);
+
+		let total: Balance = tips.iter().map(|(_, amount)| amount.0).sum();
+		let ft_metadata = match env::promise_result(0) {
+			PromiseResult::Successful(val) => from_slice::<FungibleTokenMetadata>(&val).ok(),
+			_ => None,
+		};
+		let ft_metadata = match ft_metadata {
+			Some(ft_metadata) => ft_metadata,
+			None => return U128(total),
+		};
+
+		let symbol = ft_metadata.get_symbol();
+		let decimals = *ft_metadata.get_decimals() as usize;
+		let mut refund: Balance = Zero::zero();
+
+		self.create_ft_identifier(&ft_identifier, symbol, &(decimals as u8));
+
+		for (tips_balance_info, amount) in tips {
+			let amount = amount.0;
+			let reason = if tips_balance_info.get_ft_identifier() != ft_identifier {
+				Some("InvalidFtIdentifier")
+			} else {
+				self.tip_rejection_reason(&sender, &tips_balance_info, amount)
+			};
+
+			if let Some(reason) = reason {
+				log!("{}: refunding {} to {}", reason, amount, sender);
+				refund += amount;
+				continue
+			}
+
+			let amount_str = amount.to_string();
+			let reference = tips_balance_info.reference();
+
+			let tip_id = self.create_tips_balance(
+				&sender,
+				&tips_balance_info,
+				&amount,
+				memo.clone(),
+				TipKind::Direct,
+			);
+			self.tip_log(&sender, &amount_str, symbol, decimals, Some(reference));
+			self.add_round_contribution(&sender, &tips_balance_info, amount, tip_id);
+			self.match_tip(&sender, &tips_balance_info, amount, tip_id);
+		}
+
+		U128(refund)
+	}
+
 	pub(crate) fn internal_resolve_claim_tip(
 		&mut self,
 		tips_balance: TipsBalance,
-		amount: Balance,
+		payouts: Vec<Payout>,
 	) {
-		require!(env::promise_results_count() == 1);
+		require!(env::promise_results_count() == payouts.len() as u64);
 
-		if let PromiseResult::Successful(_) = env::promise_result(0) {
-			let tips_balance_key = tips_balance.key();
-			let ft_identifier = tips_balance.get_ft_identifier().to_string();
+		let paid_payouts = self.paid_payouts(&payouts, 0);
 
-			if let Some(ft_identifier_info) = self.ft_identifiers.get(&ft_identifier) {
-				let symbol = ft_identifier_info.get_symbol();
-				let decimals = ft_identifier_info.get_decimals() as usize;
-				let receiver = tips_balance.get_account_id().clone().unwrap();
-				let amount_str = amount.to_string();
+		self.claim_paid_payouts(tips_balance, &payouts, paid_payouts);
+	}
 
-				self.tip_log(&receiver, &amount_str, symbol, decimals, None);
-			}
+	pub(crate) fn internal_resolve_batch_claim_tip(
+		&mut self,
+		tips_balances: Vec<TipsBalance>,
+		payouts: Vec<Vec<Payout>>,
+	) {
+		let total_payouts: usize = payouts.iter().map(|payouts| payouts.len()).sum();
+
+		require!(env::promise_results_count() == total_payouts as u64);
+
+		let mut offset = 0;
 
-			self.tips_balances.insert(&tips_balance_key, &tips_balance);
+		for (tips_balance, payouts) in tips_balances.into_iter().zip(payouts) {
+			let paid_payouts = self.paid_payouts(&payouts, offset);
+
+			offset += payouts.len() as u64;
+
+			self.claim_paid_payouts(tips_balance, &payouts, paid_payouts);
 		}
 	}
 
-	pub(crate) fn internal_resolve_batch_claim_tip(&mut self, tips_balances: Vec<TipsBalance>) {
-		require!(env::promise_results_count() == tips_balances.len() as u64);
+	/// The claimed amount was debited before the transfers, so the payouts that failed are put
+	/// back into the latest stored balance and stay claimable.
+	pub(crate) fn claim_paid_payouts(
+		&mut self,
+		tips_balance: TipsBalance,
+		payouts: &[Payout],
+		paid_payouts: Vec<Payout>,
+	) {
+		let key = tips_balance.key();
+		let ft_identifier = tips_balance.get_ft_identifier().to_string();
+		let total: Balance = payouts.iter().map(|payout| payout.amount.0).sum();
+		let paid: Balance = paid_payouts.iter().map(|payout| payout.amount.0).sum();
 
-		for tips_balance in tips_balances {
-			let key = tips_balance.key();
-			let amount = tips_balance.get_amount();
-			let tips_balance = tips_balance.set_balance(Zero::zero());
-			let ft_identifier = tips_balance.get_ft_identifier().to_string();
+		if total > paid {
+			let tips_balance = self.tips_balances.get(&key).unwrap_or(tips_balance);
 
-			if let Some(ft_info) = self.ft_identifiers.get(&ft_identifier) {
-				let symbol = ft_info.get_symbol();
-				let decimals = ft_info.get_decimals() as usize;
-				let receiver = tips_balance.get_account_id().clone().unwrap();
-				let amount_str = amount.to_string();
+			self.tips_balances.insert(&key, &tips_balance.add_balance(total - paid));
+		}
+
+		if let Some(ft_info) = self.ft_identifiers.get(&ft_identifier) {
+			let symbol = ft_info.get_symbol();
+			let decimals = ft_info.get_decimals() as usize;
+
+			for payout in paid_payouts {
+				let amount_str = payout.amount.0.to_string();
 
-				self.tip_log(&receiver, &amount_str, symbol, decimals, None);
+				self.tip_log(&payout.account_id, &amount_str, symbol, decimals, None);
 			}
+		}
+	}
 
-			self.tips_balances.insert(&key, &tips_balance);
+	/// Gives back a tx fee that was taken out of the native balance but could not be paid.
+	pub(crate) fn restore_tx_fee(&mut self, native_key: &TipsBalanceKey, tx_fee: Balance) {
+		if let Some(native_tips_balance) = self.tips_balances.get(native_key) {
+			self.tips_balances.insert(native_key, &native_tips_balance.add_balance(tx_fee));
 		}
+
+		log!("TxFeeNotPaid: reference not claimed");
 	}
 
+	/// Merges the tips once the tx fee is paid. The balances are read again here, so tips that
+	/// were cancelled, refunded or credited while the fee was in flight are accounted for.
 	pub(crate) fn internal_resolve_claim_reference(
 		&mut self,
-		secondary_key: TipsBalanceKey,
-		main_balance: TipsBalance,
-		native_tips_balance: Option<TipsBalance>,
+		tips_balance_info: TipsBalanceInfo,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+		account_id: AccountId,
+		tx_fee: Balance,
 	) {
 		require!(env::promise_results_count() == 1);
 
+		let server_id = tips_balance_info.get_server_id();
+		let native_key = TipsBalanceKey::new(server_id, &reference_type, &reference_id, "native");
+
 		match env::promise_result(0) {
 			PromiseResult::Successful(_) => {
-				if let Some(native) = native_tips_balance {
-					let native_key = native.key();
-					self.tips_balances.insert(&native_key, &native);
+				let main_balance = self
+					.calculate_tips(&tips_balance_info, &reference_type, &reference_id)
+					.set_account_id(&account_id);
+
+				if main_balance.key() != native_key {
+					if let Some(native_tips_balance) = self.tips_balances.get(&native_key) {
+						let native_tips_balance = native_tips_balance.set_account_id(&account_id);
+
+						self.tips_balances.insert(&native_key, &native_tips_balance);
+					}
 				}
 
-				self.claim_tips_balance(&secondary_key, &main_balance);
+				self.claim_tips_balance(&tips_balance_info.key(), &main_balance);
 			},
-			_ => env::panic_str("TokenNotFound"),
+			_ => self.restore_tx_fee(&native_key, tx_fee),
 		};
 	}
 
 	pub(crate) fn internal_resolve_batch_claim_reference(
 		&mut self,
-		secondary_keys: Vec<TipsBalanceKey>,
-		main_balances: Vec<TipsBalance>,
+		reference_type: ReferenceType,
+		reference_ids: Vec<ReferenceId>,
+		main_ref_type: ReferenceType,
+		main_ref_id: ReferenceId,
+		account_id: AccountId,
+		tx_fee: Balance,
 	) {
 		require!(env::promise_results_count() == 1);
 
 		match env::promise_result(0) {
 			PromiseResult::Successful(_) => {
+				let (main_balances, secondary_keys) = self.batch_calculate_tips(
+					&reference_type,
+					&reference_ids,
+					&main_ref_type,
+					&main_ref_id,
+					&account_id,
+				);
+
 				self.batch_claim_tips_balance(&secondary_keys, &main_balances);
 			},
-			_ => env::panic_str("TokenNotFound"),
+			_ => {
+				let server_id = env::signer_account_id();
+				let native_key =
+					TipsBalanceKey::new(&server_id, &main_ref_type, &main_ref_id, "native");
+
+				self.restore_tx_fee(&native_key, tx_fee);
+			},
 		};
 	}
 }
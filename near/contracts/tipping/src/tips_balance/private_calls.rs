@@ -8,6 +8,7 @@ impl Tipping {
 		tips_balance_info: TipsBalanceInfo,
 		sender: AccountId,
 		amount: Balance,
+		memo: Option<String>,
 	) -> PromiseOrValue<U128> {
 		let ft_identifier = tips_balance_info.get_ft_id().expect("NotAnAccountId");
 
@@ -17,46 +18,106 @@ impl Tipping {
 				sender,
 				tips_balance_info,
 				amount,
+				memo,
 			))
 			.into()
 	}
 
+	#[private]
+	pub fn send_tips_by_ft(
+		&mut self,
+		tips: Vec<BatchTip>,
+		sender: AccountId,
+		amount: Balance,
+		memo: Option<String>,
+	) -> PromiseOrValue<U128> {
+		let ft_identifier = env::predecessor_account_id();
+
+		self.assert_batch_tips(&tips, amount);
+		self.assert_memo(&memo);
+
+		ft_contract::ext(ft_identifier.clone())
+			.ft_metadata()
+			.then(Self::ext(env::current_account_id()).resolve_send_tips(
+				sender,
+				ft_identifier.to_string(),
+				tips,
+				memo,
+			))
+			.into()
+	}
+
+	#[private]
+	pub fn resolve_send_tips(
+		&mut self,
+		sender: AccountId,
+		ft_identifier: FtIdentifier,
+		tips: Vec<BatchTip>,
+		memo: Option<String>,
+	) -> U128 {
+		self.internal_resolve_send_tips(sender, ft_identifier, tips, memo)
+	}
+
 	#[private]
 	pub fn resolve_send_tip(
 		&mut self,
 		sender: AccountId,
 		tips_balance_info: TipsBalanceInfo,
 		amount: Balance,
+		memo: Option<String>,
 	) -> U128 {
-		self.internal_resolve_send_tip(sender, tips_balance_info, amount)
+		self.internal_resolve_send_tip(sender, tips_balance_info, amount, memo)
 	}
 
 	#[private]
-	pub fn resolve_claim_tip(&mut self, tips_balance: TipsBalance, amount: Balance) {
-		self.internal_resolve_claim_tip(tips_balance, amount);
+	pub fn resolve_claim_tip(&mut self, tips_balance: TipsBalance, payouts: Vec<Payout>) {
+		self.internal_resolve_claim_tip(tips_balance, payouts);
 	}
 
 	#[private]
-	pub fn resolve_batch_claim_tip(&mut self, tips_balances: Vec<TipsBalance>) {
-		self.internal_resolve_batch_claim_tip(tips_balances);
+	pub fn resolve_batch_claim_tip(
+		&mut self,
+		tips_balances: Vec<TipsBalance>,
+		payouts: Vec<Vec<Payout>>,
+	) {
+		self.internal_resolve_batch_claim_tip(tips_balances, payouts);
 	}
 
 	#[private]
 	pub fn resolve_claim_reference(
 		&mut self,
-		secondary_key: TipsBalanceKey,
-		main_balance: TipsBalance,
-		native_tips_balance: Option<TipsBalance>,
+		tips_balance_info: TipsBalanceInfo,
+		reference_type: ReferenceType,
+		reference_id: ReferenceId,
+		account_id: AccountId,
+		tx_fee: U128,
 	) {
-		self.internal_resolve_claim_reference(secondary_key, main_balance, native_tips_balance);
+		self.internal_resolve_claim_reference(
+			tips_balance_info,
+			reference_type,
+			reference_id,
+			account_id,
+			tx_fee.0,
+		);
 	}
 
 	#[private]
 	pub fn resolve_batch_claim_reference(
 		&mut self,
-		secondary_keys: Vec<TipsBalanceKey>,
-		main_balances: Vec<TipsBalance>,
+		reference_type: ReferenceType,
+		reference_ids: Vec<ReferenceId>,
+		main_ref_type: ReferenceType,
+		main_ref_id: ReferenceId,
+		account_id: AccountId,
+		tx_fee: U128,
 	) {
-		self.internal_resolve_batch_claim_reference(secondary_keys, main_balances);
+		self.internal_resolve_batch_claim_reference(
+			reference_type,
+			reference_ids,
+			main_ref_type,
+			main_ref_id,
+			account_id,
+			tx_fee.0,
+		);
 	}
 }
@@ -2,8 +2,9 @@
 mod tips_balance_tests {
 	use crate::*;
 	use near_sdk::{
+		serde_json::to_vec,
 		test_utils::{accounts, VMContextBuilder},
-		testing_env,
+		testing_env, RuntimeFeesConfig, VMConfig,
 	};
 
 	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
@@ -29,10 +30,10 @@ mod tips_balance_tests {
 
 		// Send tip to user-user_id
 		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(tip).build());
-		contract.send_tip(tips_balance_info.clone());
+		contract.send_tip(tips_balance_info.clone(), None);
 
 		// Test
-		let expected_tips_balance = TipsBalance::new(&tips_balance_info).set_balance(tip);
+		let expected_tips_balance = TipsBalance::new(&tips_balance_info).add_tip(tip);
 		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap();
 		let formatted_tips_balance = TipsBalanceWithFormattedBalance {
 			tips_balance: expected_tips_balance,
@@ -44,6 +45,43 @@ mod tips_balance_tests {
 		assert_eq!(formatted_tips_balance, tips_balance);
 	}
 
+	#[test]
+	fn send_tip_updates_counters_and_timestamps() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+		let tip: Balance = 100000000000000000000000; // 0.1 NEAR
+
+		// Send two tips to user-user_id
+		testing_env!(context
+			.predecessor_account_id(accounts(3))
+			.block_timestamp(10)
+			.attached_deposit(tip)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		testing_env!(context
+			.predecessor_account_id(accounts(2))
+			.block_timestamp(20)
+			.attached_deposit(tip)
+			.build());
+		contract.send_tip(tips_balance_info.clone(), None);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info).unwrap().tips_balance;
+
+		assert_eq!(tips_balance.get_amount(), tip * 2);
+		assert_eq!(tips_balance.get_total_amount(), tip * 2);
+		assert_eq!(tips_balance.get_tip_count(), 2);
+		assert_eq!(tips_balance.get_first_tipped_at(), Some(10));
+		assert_eq!(tips_balance.get_last_tipped_at(), Some(20));
+		assert_eq!(tips_balance.get_last_claimed_at(), None);
+	}
+
 	#[test]
 	#[should_panic(expected = "OnlyForNear")]
 	fn cant_send_tip_when_tipping_with_fungible_token() {
@@ -58,7 +96,7 @@ mod tips_balance_tests {
 
 		// Send tip to user-user_id
 		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
-		contract.send_tip(tips_balance_info);
+		contract.send_tip(tips_balance_info, None);
 	}
 
 	#[test]
@@ -74,7 +112,7 @@ mod tips_balance_tests {
 
 		// Send tip to user-user_id
 		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(0).build());
-		contract.send_tip(tips_balance_info);
+		contract.send_tip(tips_balance_info, None);
 	}
 
 	#[test]
@@ -94,7 +132,7 @@ mod tips_balance_tests {
 			.account_balance(0)
 			.attached_deposit(10)
 			.build());
-		contract.send_tip(tips_balance_info);
+		contract.send_tip(tips_balance_info, None);
 	}
 
 	#[test]
@@ -111,7 +149,7 @@ mod tips_balance_tests {
 
 		// Send tip to user-user_id
 		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(tip).build());
-		contract.send_tip(tips_balance_info_1.clone());
+		contract.send_tip(tips_balance_info_1.clone(), None);
 
 		// Claim tip
 		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(1).build());
@@ -133,4 +171,189 @@ mod tips_balance_tests {
 		testing_env!(context.predecessor_account_id(accounts(5)).attached_deposit(1).build());
 		contract.claim_tip(tips_balance_info);
 	}
+
+	#[test]
+	#[should_panic(expected = "TipsMustMoreThanMinimum")]
+	fn cant_send_tip_below_token_minimum() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.set_tip_limit(String::from("native"), Some(U128(100)), None);
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send tip to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(99).build());
+		contract.send_tip(tips_balance_info, None);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipsMustLessThanMaximum")]
+	fn cant_send_tip_above_token_maximum() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.set_tip_limit(String::from("native"), None, Some(U128(100)));
+
+		// Payload
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "user", "user_id", "native");
+
+		// Send tip to user-user_id
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(101).build());
+		contract.send_tip(tips_balance_info, None);
+	}
+
+	#[test]
+	fn send_tips_works() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+		let tips = vec![(post_1.clone(), U128(10)), (post_2.clone(), U128(20))];
+
+		// Send tips to post_1 and post_2
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(30).build());
+		let tip_ids = contract.send_tips(tips, None);
+
+		// Test
+		assert_eq!(tip_ids, vec![0, 1]);
+		assert_eq!(contract.get_tips_balance(post_1).unwrap().tips_balance.get_amount(), 10);
+		assert_eq!(contract.get_tips_balance(post_2).unwrap().tips_balance.get_amount(), 20);
+	}
+
+	#[test]
+	#[should_panic(expected = "InvalidTipsAmount")]
+	fn cant_send_tips_when_deposit_not_equal_to_sum() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+		let tips = vec![(post_1, U128(10)), (post_2, U128(20))];
+
+		// Send tips to post_1 and post_2
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(31).build());
+		contract.send_tips(tips, None);
+	}
+
+	#[test]
+	fn tip_message_supports_single_and_batch_formats() {
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "ft.near");
+		let single = r#"{"server_id":"eugene","reference_type":"post","reference_id":"post_1","ft_identifier":"ft.near"}"#;
+		let batch = r#"{"version":2,"type":"batch","tips":[[{"server_id":"eugene","reference_type":"post","reference_id":"post_1","ft_identifier":"ft.near"},"10"]],"memo":null}"#;
+
+		let single = from_str::<TipMessage>(single).unwrap();
+		let batch = from_str::<TipMessage>(batch).unwrap();
+
+		assert_eq!(
+			single,
+			TipMessage::Single(SingleTipMessage {
+				tips_balance_info: tips_balance_info.clone(),
+				memo: None
+			})
+		);
+		assert_eq!(
+			batch,
+			TipMessage::Versioned(VersionedTipMessage {
+				version: 2,
+				action: TipAction::Batch { tips: vec![(tips_balance_info, U128(10))], memo: None },
+			})
+		);
+	}
+
+	#[test]
+	fn resolve_send_tips_refunds_invalid_tips() {
+		// Initialize contract
+		let context = get_context(accounts(0));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+		contract.block_reference(
+			accounts(4),
+			String::from("post"),
+			String::from("post_2"),
+			String::from("Abuse"),
+		);
+
+		// Payload
+		let ft_identifier = String::from("ft.near");
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", &ft_identifier);
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", &ft_identifier);
+		let post_3 = TipsBalanceInfo::new(&accounts(4), "post", "post_3", "other.near");
+		let tips = vec![(post_1.clone(), U128(10)), (post_2, U128(20)), (post_3, U128(30))];
+		let ft_metadata = FungibleTokenMetadata {
+			spec: String::from("ft-1.0.0"),
+			name: String::from("Fungible Token"),
+			symbol: String::from("FT"),
+			icon: None,
+			reference: None,
+			reference_hash: None,
+			decimals: 18,
+		};
+
+		// Resolve a batch of fungible token tips
+		testing_env!(
+			context.build(),
+			VMConfig::test(),
+			RuntimeFeesConfig::test(),
+			Default::default(),
+			vec![PromiseResult::Successful(to_vec(&ft_metadata).unwrap())],
+		);
+		let refund = contract.resolve_send_tips(accounts(3), ft_identifier, tips, None);
+
+		// Test
+		assert_eq!(refund, U128(50));
+		assert_eq!(contract.get_tips_balance(post_1).unwrap().tips_balance.get_amount(), 10);
+	}
+
+	#[test]
+	fn send_split_tip_gives_dust_to_first_share() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+		let post_3 = TipsBalanceInfo::new(&accounts(4), "post", "post_3", "native");
+		let shares = vec![(post_1.clone(), 3333), (post_2.clone(), 3333), (post_3.clone(), 3334)];
+
+		// Split a tip across post_1, post_2 and post_3
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		let tip_ids = contract.send_split_tip(shares, None);
+
+		// Test
+		assert_eq!(tip_ids.len(), 3);
+		assert_eq!(contract.get_tips_balance(post_1).unwrap().tips_balance.get_amount(), 34);
+		assert_eq!(contract.get_tips_balance(post_2).unwrap().tips_balance.get_amount(), 33);
+		assert_eq!(contract.get_tips_balance(post_3).unwrap().tips_balance.get_amount(), 33);
+	}
+
+	#[test]
+	#[should_panic(expected = "InvalidSplitWeight")]
+	fn cant_send_split_tip_when_weights_not_total() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// Payload
+		let post_1 = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+		let post_2 = TipsBalanceInfo::new(&accounts(4), "post", "post_2", "native");
+		let shares = vec![(post_1, 5000), (post_2, 4999)];
+
+		// Split a tip across post_1 and post_2
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.send_split_tip(shares, None);
+	}
 }
@@ -35,6 +35,11 @@ pub struct TipsBalance {
 	tips_balance_info: TipsBalanceInfo,
 	account_id: Option<AccountId>,
 	amount: Balance,
+	total_amount: Balance,
+	tip_count: u64,
+	first_tipped_at: Option<Timestamp>,
+	last_tipped_at: Option<Timestamp>,
+	last_claimed_at: Option<Timestamp>,
 }
 impl TipsBalance {
 	pub fn new(tips_balance_info: &TipsBalanceInfo) -> Self {
@@ -42,6 +47,11 @@ impl TipsBalance {
 			tips_balance_info: tips_balance_info.clone(),
 			account_id: None,
 			amount: Zero::zero(),
+			total_amount: Zero::zero(),
+			tip_count: Zero::zero(),
+			first_tipped_at: None,
+			last_tipped_at: None,
+			last_claimed_at: None,
 		}
 	}
 
@@ -77,16 +87,96 @@ impl TipsBalance {
 		(self.amount).to_string()
 	}
 
+	pub fn get_total_amount(&self) -> Balance {
+		self.total_amount
+	}
+
+	pub fn get_tip_count(&self) -> u64 {
+		self.tip_count
+	}
+
+	pub fn get_first_tipped_at(&self) -> Option<Timestamp> {
+		self.first_tipped_at
+	}
+
+	pub fn get_last_tipped_at(&self) -> Option<Timestamp> {
+		self.last_tipped_at
+	}
+
+	pub fn get_last_claimed_at(&self) -> Option<Timestamp> {
+		self.last_claimed_at
+	}
+
 	pub fn add_balance(mut self, amount: Balance) -> Self {
 		self.amount += amount;
 		self
 	}
 
+	/// Credits a single tip and updates the lifetime counters and timestamps.
+	pub fn add_tip(mut self, amount: Balance) -> Self {
+		let now = env::block_timestamp();
+
+		self.amount += amount;
+		self.total_amount += amount;
+		self.tip_count += 1;
+		self.first_tipped_at = Some(self.first_tipped_at.unwrap_or(now));
+		self.last_tipped_at = Some(now);
+		self
+	}
+
+	/// Reverts a single tip that the sender cancelled.
+	pub fn remove_tip(mut self, amount: Balance) -> Self {
+		self.amount = self.amount.saturating_sub(amount);
+		self.total_amount = self.total_amount.saturating_sub(amount);
+		self.tip_count = self.tip_count.saturating_sub(1);
+		self
+	}
+
+	/// Puts back a tip whose cancellation could not be paid out.
+	pub fn restore_tip(mut self, amount: Balance) -> Self {
+		self.amount += amount;
+		self.total_amount += amount;
+		self.tip_count += 1;
+		self
+	}
+
+	/// Folds the lifetime counters of another balance into this one, used when an unclaimed
+	/// reference is merged into a claimed reference.
+	pub fn merge_stats(mut self, other: &TipsBalance) -> Self {
+		self.total_amount += other.total_amount;
+		self.tip_count += other.tip_count;
+		self.first_tipped_at = match (self.first_tipped_at, other.first_tipped_at) {
+			(Some(a), Some(b)) => Some(min(a, b)),
+			(a, b) => a.or(b),
+		};
+		self.last_tipped_at = max(self.last_tipped_at, other.last_tipped_at);
+		self
+	}
+
+	/// Empties a balance that has been merged into a claimed reference. The lifetime counters move
+	/// along with the amount, so they are reset here to avoid counting them twice.
+	pub fn sweep(mut self) -> Self {
+		self.amount = Zero::zero();
+		self.total_amount = Zero::zero();
+		self.tip_count = Zero::zero();
+		self.set_claimed()
+	}
+
+	pub fn set_claimed(mut self) -> Self {
+		self.last_claimed_at = Some(env::block_timestamp());
+		self
+	}
+
 	pub fn set_balance(mut self, amount: Balance) -> Self {
 		self.amount = amount;
 		self
 	}
 
+	pub fn set_total_amount(mut self, total_amount: Balance) -> Self {
+		self.total_amount = total_amount;
+		self
+	}
+
 	pub fn set_account_id(mut self, account_id: &AccountId) -> Self {
 		self.account_id = Some(account_id.clone());
 		self
@@ -148,11 +238,7 @@ impl TipsBalanceInfo {
 	}
 
 	pub fn get_ft_id(&self) -> Option<AccountId> {
-		let ft_identifier = (&self.ft_identifier).to_string();
-		match ft_identifier.parse::<AccountId>() {
-			Ok(ft_identifier) => Some(ft_identifier),
-			Err(_) => None,
-		}
+		self.ft_identifier.parse::<AccountId>().ok()
 	}
 
 	pub fn reference(&self) -> String {
@@ -179,6 +265,47 @@ impl TipsBalanceInfo {
 	}
 }
 
+pub const MAX_BATCH_TIPS: usize = 20;
+pub const TIP_MESSAGE_VERSION: u8 = 2;
+pub const TOTAL_SPLIT_WEIGHT: u16 = 10_000;
+
+pub type BatchTip = (TipsBalanceInfo, U128);
+/// A recipient of a split tip and its weight in basis points.
+pub type SplitShare = (TipsBalanceInfo, u16);
+
+/// Message attached to `ft_transfer_call`. The original format is the fields of `TipsBalanceInfo`
+/// with an optional memo, newer formats carry a `version` and a `type`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde", untagged)]
+pub enum TipMessage {
+	Versioned(VersionedTipMessage),
+	Single(SingleTipMessage),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SingleTipMessage {
+	#[serde(flatten)]
+	pub tips_balance_info: TipsBalanceInfo,
+	pub memo: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VersionedTipMessage {
+	pub version: u8,
+	#[serde(flatten)]
+	pub action: TipAction,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde", tag = "type", rename_all = "snake_case")]
+pub enum TipAction {
+	Batch { tips: Vec<BatchTip>, memo: Option<String> },
+	Split { shares: Vec<SplitShare>, memo: Option<String> },
+	Deposit,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 #[serde(crate = "near_sdk::serde")]
 pub struct TipsBalanceWithFormattedBalance {
@@ -228,6 +355,51 @@ impl FtIdentifierInfo {
 	}
 }
 
+/// Bounds for a single tip in a token, kept next to its `FtIdentifierInfo`.
+#[derive(
+	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TipLimit {
+	min_amount: Option<U128>,
+	max_amount: Option<U128>,
+}
+impl TipLimit {
+	pub fn new(min_amount: Option<U128>, max_amount: Option<U128>) -> Self {
+		Self { min_amount, max_amount }
+	}
+
+	pub fn get_min_amount(&self) -> Option<Balance> {
+		self.min_amount.map(|amount| amount.0)
+	}
+
+	pub fn get_max_amount(&self) -> Option<Balance> {
+		self.max_amount.map(|amount| amount.0)
+	}
+
+	/// Returns the reason an amount is out of bounds, if it is.
+	pub fn check(&self, amount: Balance) -> Option<&'static str> {
+		if amount == 0 || matches!(self.get_min_amount(), Some(min) if amount < min) {
+			return Some("TipsMustMoreThanMinimum")
+		}
+
+		if matches!(self.get_max_amount(), Some(max) if amount > max) {
+			return Some("TipsMustLessThanMaximum")
+		}
+
+		None
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TipLimitWithFormattedAmount {
+	pub ft_identifier: FtIdentifier,
+	pub tip_limit: TipLimit,
+	pub formatted_min_amount: Option<String>,
+	pub formatted_max_amount: Option<String>,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[serde(crate = "near_sdk::serde")]
 pub struct FtIdentifierWithPagination {
@@ -7,8 +7,17 @@ impl Tipping {
 		tips_balance_info: TipsBalanceInfo,
 	) -> Option<TipsBalanceWithFormattedBalance> {
 		let ft_identifier = tips_balance_info.get_ft_identifier().to_string();
-		let tips_balance = self.tips_balances.get(&tips_balance_info.key())?;
 		let ft_identifier_info = self.ft_identifiers.get(&ft_identifier)?;
+		let tips_balance = self.tips_balances.get(&tips_balance_info.key());
+		let has_streams =
+			self.stream_ids.contains_key(&StreamIndex::Reference(tips_balance_info.key()));
+
+		if tips_balance.is_none() && !has_streams {
+			return None
+		}
+
+		let tips_balance = tips_balance.unwrap_or_else(|| TipsBalance::new(&tips_balance_info));
+		let tips_balance = self.with_streamed(tips_balance);
 
 		let symbol = ft_identifier_info.get_symbol();
 		let decimals = ft_identifier_info.get_decimals().into();
@@ -56,7 +65,7 @@ impl Tipping {
 			.filter_map(|index| {
 				if let Some(ft_identifier_info) = values.get(index) {
 					let ft_identifier = ft_identifier_info.get_ft_identifier();
-					let mut total_tips: Balance = Zero::zero();
+					let mut unclaimed_tips_balances = Vec::<TipsBalance>::new();
 					let mut unclaimed_reference_ids = Vec::<String>::new();
 
 					for reference_id in reference_ids.iter() {
@@ -71,9 +80,9 @@ impl Tipping {
 
 						if let Some(tips_balance) = tips_balance {
 							if tips_balance.get_amount() > 0 {
-								total_tips += tips_balance.get_amount();
 								unclaimed_reference_ids
 									.push(tips_balance.get_reference_id().to_string());
+								unclaimed_tips_balances.push(tips_balance);
 							}
 						}
 					}
@@ -87,20 +96,21 @@ impl Tipping {
 						ft_identifier,
 					);
 
-					let tips_balance = self
-						.tips_balances
-						.get(&key)
-						.unwrap_or_else(|| {
-							let tips_balance_info = TipsBalanceInfo::new(
-								&server_id,
-								&main_ref_type,
-								&main_ref_id,
-								ft_identifier,
-							);
-
-							TipsBalance::new(&tips_balance_info)
-						})
-						.add_balance(total_tips);
+					let tips_balance = self.tips_balances.get(&key).unwrap_or_else(|| {
+						let tips_balance_info = TipsBalanceInfo::new(
+							&server_id,
+							&main_ref_type,
+							&main_ref_id,
+							ft_identifier,
+						);
+
+						TipsBalance::new(&tips_balance_info)
+					});
+
+					let tips_balance =
+						unclaimed_tips_balances.iter().fold(tips_balance, |main, unclaimed| {
+							main.add_balance(unclaimed.get_amount()).merge_stats(unclaimed)
+						});
 
 					let balance = tips_balance.get_amount_str();
 					let formatted = self.formatted_balance(balance.as_str(), decimals);
@@ -121,4 +131,18 @@ impl Tipping {
 
 		TipsBalanceWithPagination { data, meta }
 	}
+
+	pub fn get_tip_limit(&self, ft_identifier: FtIdentifier) -> TipLimitWithFormattedAmount {
+		let tip_limit = self.tip_limits.get(&ft_identifier).unwrap_or_default();
+		let format = |amount: Balance| self.formatted_amount_of(&ft_identifier, amount).1;
+		let formatted_min_amount = tip_limit.get_min_amount().map(format);
+		let formatted_max_amount = tip_limit.get_max_amount().map(format);
+
+		TipLimitWithFormattedAmount {
+			ft_identifier: ft_identifier.clone(),
+			tip_limit,
+			formatted_min_amount,
+			formatted_max_amount,
+		}
+	}
 }
@@ -1,6 +1,9 @@
 use crate::*;
+use uint::construct_uint;
 
 pub const ONE_YOCTO: Balance = 1;
+pub const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+pub const NANOSECONDS_PER_DAY: u64 = 86_400 * NANOSECONDS_PER_SECOND;
 
 #[derive(
 	BorshDeserialize, BorshSerialize, Serialize, Deserialize, Default, Clone, PartialEq, Eq, Debug,
@@ -19,4 +22,63 @@ pub struct Metadata {
 pub enum StorageKeys {
 	FtIdentifierInfo,
 	TipsBalance,
+	TipRecord,
+	TipRecordQueue,
+	TipRecordId,
+	SupporterTotal,
+	TopSupporters,
+	TopReferences,
+	RefundExpiryDays,
+	ContributionPool,
+	Contribution,
+	PausedTokens,
+	Pausers,
+	TipLimit,
+	Moderators,
+	BlockedReferences,
+	BlockedSenders,
+	RateLimits,
+	RateLimitExemptions,
+	RateWindows,
+	RevenueSplits,
+	AutoForwardAccounts,
+	Deposits,
+	Allowances,
+	VoucherKeys,
+	VoucherStates,
+	Subscriptions,
+	SubscriptionIds,
+	Streams,
+	StreamIds,
+	ConditionalTips,
+	ConditionalTipIds,
+	Campaigns,
+	CampaignContributions,
+	MatchingPools,
+	MatchingPoolIds,
+	MatchedAmounts,
+	FundingRounds,
+	FundingRoundIds,
+	RoundReferences,
+	RoundContributions,
+	Bounties,
+	BountyIds,
+	GiftCodes,
+	GiftCodeHashes,
+	GiftCodeCommitments,
+	RevealedGiftCodes,
+	ReferenceKeys,
+	KeyClaims,
+}
+
+pub use big_uint::U256;
+
+#[allow(clippy::all)]
+mod big_uint {
+	use super::construct_uint;
+
+	construct_uint! {
+		/// 256-bit unsigned integer, used where multiplying two balances would overflow `u128`.
+		pub struct U256(4);
+	}
 }
@@ -0,0 +1,29 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	/// Registers the ed25519 key the caller signs vouchers with, replacing the previous one.
+	pub fn register_voucher_key(&mut self, public_key: PublicKey) {
+		assert!(public_key.curve_type() == CurveType::ED25519, "InvalidPublicKey");
+
+		self.voucher_keys.insert(&env::predecessor_account_id(), &public_key);
+	}
+
+	pub fn remove_voucher_key(&mut self) {
+		self.voucher_keys
+			.remove(&env::predecessor_account_id())
+			.expect("VoucherKeyNotExists");
+	}
+
+	/// Credits the signed vouchers from the deposits of their senders. Invalid, already settled
+	/// or unfunded vouchers are skipped.
+	pub fn settle_vouchers(&mut self, vouchers: Vec<SignedVoucher>) -> Vec<TipId> {
+		assert!(vouchers.len() <= MAX_SETTLED_VOUCHERS, "TooManyVouchers");
+		self.assert_not_paused(PauseFeature::Tipping, None);
+
+		vouchers
+			.iter()
+			.filter_map(|signed_voucher| self.settle_voucher(signed_voucher))
+			.collect()
+	}
+}
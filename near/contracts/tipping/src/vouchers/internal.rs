@@ -0,0 +1,79 @@
+use crate::*;
+use ed25519_dalek::{PublicKey as VoucherPublicKey, Signature};
+
+impl Tipping {
+	pub(crate) fn is_valid_voucher_signature(&self, signed_voucher: &SignedVoucher) -> bool {
+		let public_key = match self.voucher_keys.get(&signed_voucher.voucher.sender) {
+			Some(public_key) => public_key,
+			None => return false,
+		};
+		let public_key = match VoucherPublicKey::from_bytes(&public_key.as_bytes()[1..]) {
+			Ok(public_key) => public_key,
+			Err(_) => return false,
+		};
+		let signature = match Signature::try_from(signed_voucher.signature.0.as_slice()) {
+			Ok(signature) => signature,
+			Err(_) => return false,
+		};
+
+		public_key.verify_strict(&signed_voucher.voucher.message(), &signature).is_ok()
+	}
+
+	/// Returns the reason a voucher cannot be settled, if it cannot, and the amount it credits.
+	pub(crate) fn voucher_rejection_reason(
+		&self,
+		signed_voucher: &SignedVoucher,
+	) -> Result<Balance, &'static str> {
+		let voucher = &signed_voucher.voucher;
+		let sender = &voucher.sender;
+		let tips_balance_info = &voucher.tips_balance_info;
+		let state_key = (sender.clone(), tips_balance_info.key());
+		let state = self.voucher_states.get(&state_key).unwrap_or_else(|| VoucherState::new(0, 0));
+		let amount = voucher.cumulative_amount.0.saturating_sub(state.get_cumulative_amount());
+		let ft_identifier = tips_balance_info.get_ft_identifier();
+
+		if !self.is_valid_voucher_signature(signed_voucher) {
+			return Err("InvalidSignature")
+		}
+
+		if voucher.nonce <= state.get_nonce() || amount.is_zero() {
+			return Err("VoucherAlreadySettled")
+		}
+
+		if self.deposit_of(sender, ft_identifier) < amount {
+			return Err("InsufficientDeposit")
+		}
+
+		match self.tip_rejection_reason(sender, tips_balance_info, amount) {
+			Some(reason) => Err(reason),
+			None => Ok(amount),
+		}
+	}
+
+	pub(crate) fn settle_voucher(&mut self, signed_voucher: &SignedVoucher) -> Option<TipId> {
+		let voucher = &signed_voucher.voucher;
+		let sender = &voucher.sender;
+		let tips_balance_info = &voucher.tips_balance_info;
+
+		let amount = match self.voucher_rejection_reason(signed_voucher) {
+			Ok(amount) => amount,
+			Err(reason) => {
+				log!("{}: skipping voucher {} of {}", reason, voucher.nonce, sender);
+				return None
+			},
+		};
+
+		let state_key = (sender.clone(), tips_balance_info.key());
+		let state = VoucherState::new(voucher.cumulative_amount.0, voucher.nonce);
+
+		self.voucher_states.insert(&state_key, &state);
+
+		Some(self.internal_tip_from_balance(
+			sender,
+			tips_balance_info,
+			amount,
+			None,
+			TipKind::Settled,
+		))
+	}
+}
@@ -0,0 +1,183 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod vouchers_tests {
+	use crate::*;
+	use ed25519_dalek::{ExpandedSecretKey, PublicKey as VoucherPublicKey, SecretKey};
+	use near_sdk::{
+		test_utils::{accounts, VMContextBuilder},
+		testing_env,
+	};
+
+	fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+		let mut builder = VMContextBuilder::new();
+		builder
+			.current_account_id(accounts(0))
+			.signer_account_id(predecessor_account_id.clone())
+			.predecessor_account_id(predecessor_account_id);
+
+		builder
+	}
+
+	fn secret_key() -> SecretKey {
+		SecretKey::from_bytes(&[7; 32]).unwrap()
+	}
+
+	fn sign(voucher: Voucher) -> SignedVoucher {
+		let secret_key = secret_key();
+		let public_key = VoucherPublicKey::from(&secret_key);
+		let signature = ExpandedSecretKey::from(&secret_key).sign(&voucher.message(), &public_key);
+
+		SignedVoucher { voucher, signature: Base64VecU8(signature.to_bytes().to_vec()) }
+	}
+
+	#[test]
+	fn settle_vouchers_credits_cumulative_difference() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and registers its voucher key
+		let public_key = VoucherPublicKey::from(&secret_key());
+		let mut public_key_bytes = vec![CurveType::ED25519 as u8];
+
+		public_key_bytes.extend_from_slice(public_key.as_bytes());
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+		contract.register_voucher_key(PublicKey::try_from(public_key_bytes).unwrap());
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Payload
+		let voucher = |cumulative_amount: Balance, nonce: u64| {
+			sign(Voucher {
+				sender: accounts(3),
+				tips_balance_info: tips_balance_info.clone(),
+				cumulative_amount: U128(cumulative_amount),
+				nonce,
+			})
+		};
+
+		// A server settles the vouchers, replaying the first one
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(0).build());
+		let tip_ids =
+			contract.settle_vouchers(vec![voucher(10, 1), voucher(25, 2), voucher(10, 1)]);
+
+		// Test
+		let tips_balance = contract.get_tips_balance(tips_balance_info.clone()).unwrap();
+		let voucher_state = contract.get_voucher_state(accounts(3), tips_balance_info).unwrap();
+
+		assert_eq!(tip_ids.len(), 2);
+		assert_eq!(tips_balance.tips_balance.get_amount(), 25);
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(75));
+		assert_eq!(voucher_state.get_nonce(), 2);
+	}
+
+	#[test]
+	#[should_panic(expected = "TipNotCancellable")]
+	fn cant_cancel_settled_voucher() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and registers its voucher key
+		let public_key = VoucherPublicKey::from(&secret_key());
+		let mut public_key_bytes = vec![CurveType::ED25519 as u8];
+
+		public_key_bytes.extend_from_slice(public_key.as_bytes());
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+		contract.register_voucher_key(PublicKey::try_from(public_key_bytes).unwrap());
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// A server settles a voucher of accounts(3)
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(0).build());
+		let tip_ids = contract.settle_vouchers(vec![sign(Voucher {
+			sender: accounts(3),
+			tips_balance_info,
+			cumulative_amount: U128(10),
+			nonce: 1,
+		})]);
+
+		// Test
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(1).build());
+		contract.cancel_tip(tip_ids[0]);
+	}
+
+	#[test]
+	fn settle_vouchers_skips_invalid_signature() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and registers its voucher key
+		let public_key = VoucherPublicKey::from(&secret_key());
+		let mut public_key_bytes = vec![CurveType::ED25519 as u8];
+
+		public_key_bytes.extend_from_slice(public_key.as_bytes());
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+		contract.register_voucher_key(PublicKey::try_from(public_key_bytes).unwrap());
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Payload
+		let mut signed_voucher = sign(Voucher {
+			sender: accounts(3),
+			tips_balance_info: tips_balance_info.clone(),
+			cumulative_amount: U128(10),
+			nonce: 1,
+		});
+
+		signed_voucher.voucher.cumulative_amount = U128(100);
+
+		// A server settles the tampered voucher
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(0).build());
+		let tip_ids = contract.settle_vouchers(vec![signed_voucher]);
+
+		// Test
+		assert!(tip_ids.is_empty());
+		assert!(contract.get_tips_balance(tips_balance_info).is_none());
+	}
+
+	#[test]
+	fn settle_vouchers_skips_unfunded_voucher() {
+		// Initialize contract
+		let mut context = get_context(accounts(1));
+		testing_env!(context.build());
+		let mut contract = Tipping::new(None);
+
+		// accounts(3) deposits and registers its voucher key
+		let public_key = VoucherPublicKey::from(&secret_key());
+		let mut public_key_bytes = vec![CurveType::ED25519 as u8];
+
+		public_key_bytes.extend_from_slice(public_key.as_bytes());
+
+		testing_env!(context.predecessor_account_id(accounts(3)).attached_deposit(100).build());
+		contract.deposit();
+		contract.register_voucher_key(PublicKey::try_from(public_key_bytes).unwrap());
+
+		let tips_balance_info = TipsBalanceInfo::new(&accounts(4), "post", "post_1", "native");
+
+		// Payload
+		let signed_voucher = sign(Voucher {
+			sender: accounts(3),
+			tips_balance_info: tips_balance_info.clone(),
+			cumulative_amount: U128(101),
+			nonce: 1,
+		});
+
+		// A server settles the voucher
+		testing_env!(context.predecessor_account_id(accounts(4)).attached_deposit(0).build());
+		let tip_ids = contract.settle_vouchers(vec![signed_voucher]);
+
+		// Test
+		assert!(tip_ids.is_empty());
+		assert_eq!(contract.get_deposit(accounts(3), String::from("native")), U128(100));
+	}
+}
@@ -0,0 +1,49 @@
+use crate::*;
+
+pub const MAX_SETTLED_VOUCHERS: usize = 10;
+
+pub type VoucherStateKey = (AccountId, TipsBalanceKey);
+
+/// Off-chain promise of a sender to a reference. The amount is the running total of everything
+/// promised to the reference, so settling an older voucher again credits nothing.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Voucher {
+	pub sender: AccountId,
+	pub tips_balance_info: TipsBalanceInfo,
+	pub cumulative_amount: U128,
+	pub nonce: u64,
+}
+impl Voucher {
+	/// Bytes signed by the sender: the borsh serialized contract account and voucher.
+	pub fn message(&self) -> Vec<u8> {
+		(env::current_account_id(), self).try_to_vec().unwrap()
+	}
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SignedVoucher {
+	pub voucher: Voucher,
+	pub signature: Base64VecU8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VoucherState {
+	cumulative_amount: U128,
+	nonce: u64,
+}
+impl VoucherState {
+	pub fn new(cumulative_amount: Balance, nonce: u64) -> Self {
+		Self { cumulative_amount: U128(cumulative_amount), nonce }
+	}
+
+	pub fn get_cumulative_amount(&self) -> Balance {
+		self.cumulative_amount.0
+	}
+
+	pub fn get_nonce(&self) -> u64 {
+		self.nonce
+	}
+}
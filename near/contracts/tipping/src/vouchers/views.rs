@@ -0,0 +1,16 @@
+use crate::*;
+
+#[near_bindgen]
+impl Tipping {
+	pub fn get_voucher_key(&self, account_id: AccountId) -> Option<PublicKey> {
+		self.voucher_keys.get(&account_id)
+	}
+
+	pub fn get_voucher_state(
+		&self,
+		sender: AccountId,
+		tips_balance_info: TipsBalanceInfo,
+	) -> Option<VoucherState> {
+		self.voucher_states.get(&(sender, tips_balance_info.key()))
+	}
+}